@@ -29,3 +29,8 @@ SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 pub mod math;
 pub mod utility;
+
+#[cfg(feature = "wasm")]
+mod test_wasm;
+#[cfg(feature = "wasm")]
+pub mod wasm;
@@ -28,7 +28,37 @@ SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 */
 
 pub mod algebra;
+pub mod approx;
 pub mod arrayalgebra;
 mod test_algebra;
+mod test_approx;
+
+pub mod dual;
+mod test_dual;
 
 pub mod graph;
+
+pub mod joint;
+mod test_joint;
+
+#[cfg(feature = "nalgebra")]
+pub mod nalgebra_interop;
+mod test_nalgebra_interop;
+
+pub mod rational;
+mod test_rational;
+
+pub mod ransac;
+mod test_ransac;
+
+pub mod rotations;
+mod test_rotations;
+
+pub mod spatial;
+mod test_spatial;
+
+pub mod sparse;
+mod test_sparse;
+
+pub mod spherical_wrist;
+mod test_spherical_wrist;
@@ -35,9 +35,9 @@ SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 //! supports the implementation of, for instance, the vector space of integer
 //! vectors of dimension N over some prime field.
 
-use std::cmp::PartialEq;
+use std::cmp::{PartialEq, PartialOrd};
 use std::marker::Copy;
-use std::ops::{Add, Mul, Neg};
+use std::ops::{Add, Div, Mul, Neg, Sub};
 
 /// Scalar trait for describing types satisfying the field axioms.
 pub trait Scalar:
@@ -59,6 +59,22 @@ pub trait Scalar:
     fn additive_inverse(self) -> Self;
 }
 
+/// Real trait for describing scalar fields that, like the real numbers, are
+/// ordered and support division, absolute values and square roots. Algorithms
+/// such as matrix inversion or normalization are written against this trait
+/// so they serve both f32 and f64.
+pub trait Real: Scalar + Sub<Self, Output = Self> + Div<Self, Output = Self> + PartialOrd {
+    /// Returns the absolute value.
+    fn abs(self) -> Self;
+
+    /// Returns the (non-negative) square root.
+    fn sqrt(self) -> Self;
+
+    /// Returns the difference between one and the next larger representable
+    /// value, i.e. the relative precision of the field.
+    fn epsilon() -> Self;
+}
+
 /// Vector trait for describing types supporting vector addition and scalar
 /// multiplication.
 pub trait Vector<Field>:
@@ -82,6 +98,70 @@ where
 {
 }
 
+/// Cross Product trait for describing 3-dimensional vectors (or encodings of
+/// them) that support the vector cross product.
+pub trait CrossProduct<Field>: Vector<Field>
+where
+    Field: Scalar,
+{
+    /// Returns the cross product of this vector with the given vector.
+    fn cross(self, rhs: Self) -> Self;
+}
+
+/// Inner Product Space trait for describing vectors equipped with an inner
+/// product, and so with lengths and distances.
+pub trait InnerProductSpace<Field>: Vector<Field>
+where
+    Field: Scalar,
+{
+    /// Returns the inner product of this vector with the given vector.
+    fn dot(self, rhs: Self) -> Field;
+
+    /// Returns the length of the vector.
+    fn norm(self) -> Field;
+
+    /// Returns the squared length of the vector, which avoids a square root.
+    fn squared_norm(self) -> Field {
+        self.dot(self)
+    }
+
+    /// Returns the length of the difference of the two vectors.
+    fn distance(self, other: Self) -> Field {
+        (self + -other).norm()
+    }
+
+    /// Returns the vector scaled to unit length, or `None` for the zero
+    /// vector.
+    fn normalize(self) -> Option<Self>;
+}
+
+/// Returns an orthonormal basis for the span of the given vectors, in order,
+/// using modified Gram-Schmidt; the k-th output spans the same subspace as the
+/// first k inputs. Returns `None` if the vectors are (numerically) linearly
+/// dependent, i.e. if some vector has less than sqrt(epsilon) of its length
+/// left after removing its components along the earlier ones.
+pub fn gram_schmidt<Field, V>(vectors: &[V]) -> Option<Vec<V>>
+where
+    Field: Real,
+    V: InnerProductSpace<Field>,
+{
+    let mut basis: Vec<V> = Vec::with_capacity(vectors.len());
+
+    for vector in vectors {
+        let mut residual = *vector;
+        for unit in basis.iter() {
+            residual = residual + -(*unit * unit.dot(residual));
+        }
+
+        if residual.norm() <= vector.norm() * Field::epsilon().sqrt() {
+            return None;
+        }
+        basis.push(residual.normalize()?);
+    }
+
+    Some(basis)
+}
+
 /// Linear Map trait for describing types that act as linear maps on vectors
 /// from one vector space to another.
 pub trait LinearMap<Field, Domain, Codomain>: Mul<Domain, Output = Codomain>
@@ -121,3 +201,33 @@ impl Scalar for f64 {
         1.0
     }
 }
+
+/// Default implementation of Real for the primitive f32.
+impl Real for f32 {
+    fn abs(self) -> f32 {
+        f32::abs(self)
+    }
+
+    fn sqrt(self) -> f32 {
+        f32::sqrt(self)
+    }
+
+    fn epsilon() -> f32 {
+        f32::EPSILON
+    }
+}
+
+/// Default implementation of Real for the primitive f64.
+impl Real for f64 {
+    fn abs(self) -> f64 {
+        f64::abs(self)
+    }
+
+    fn sqrt(self) -> f64 {
+        f64::sqrt(self)
+    }
+
+    fn epsilon() -> f64 {
+        f64::EPSILON
+    }
+}
@@ -0,0 +1,122 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+//! Approximate equality module.
+//!
+//! Floating-point results rarely compare exactly equal. This module provides
+//! the `ApproxEq` trait, which compares values within absolute, relative or
+//! ULP (units in the last place) tolerances, for scalars and for composite
+//! values such as vectors, matrices and transforms, which compare
+//! component-wise.
+
+/// Approximate equality trait.
+///
+/// Every comparison first accepts values whose absolute difference is at
+/// most `epsilon`, which is what makes values near zero comparable; the
+/// relative and ULP comparisons then additionally accept values that are
+/// close for their magnitude.
+pub trait ApproxEq {
+    /// Type of the absolute and relative tolerances.
+    type Tolerance: Copy;
+
+    /// Returns the absolute tolerance used by `approx_eq`.
+    fn default_epsilon() -> Self::Tolerance;
+
+    /// Returns the relative tolerance used by `approx_eq`.
+    fn default_max_relative() -> Self::Tolerance;
+
+    /// Checks if the absolute difference is at most `epsilon`.
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Tolerance) -> bool;
+
+    /// Checks if the absolute difference is at most `epsilon`, or at most
+    /// `max_relative` times the larger magnitude.
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Tolerance,
+        max_relative: Self::Tolerance,
+    ) -> bool;
+
+    /// Checks if the absolute difference is at most `epsilon`, or if the two
+    /// values are at most `max_ulps` representable values apart.
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Tolerance, max_ulps: u32) -> bool;
+
+    /// Checks for relative equality using the default tolerances.
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.relative_eq(other, Self::default_epsilon(), Self::default_max_relative())
+    }
+}
+
+macro_rules! float_approx_eq {
+    ($float:ty, $bits:ty) => {
+        impl ApproxEq for $float {
+            type Tolerance = $float;
+
+            fn default_epsilon() -> $float {
+                <$float>::EPSILON
+            }
+
+            fn default_max_relative() -> $float {
+                <$float>::EPSILON
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: $float) -> bool {
+                self == other || (self - other).abs() <= epsilon
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: $float, max_relative: $float) -> bool {
+                if self.abs_diff_eq(other, epsilon) {
+                    return true;
+                }
+                if self.is_infinite() || other.is_infinite() {
+                    return false;
+                }
+
+                let largest = self.abs().max(other.abs());
+                (self - other).abs() <= largest * max_relative
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: $float, max_ulps: u32) -> bool {
+                if self.abs_diff_eq(other, epsilon) {
+                    return true;
+                }
+                if self.is_sign_positive() != other.is_sign_positive() {
+                    return false;
+                }
+
+                // Same-signed floats are ordered like their bit patterns.
+                let (a, b) = (self.to_bits() as $bits, other.to_bits() as $bits);
+                a.abs_diff(b) <= max_ulps.into()
+            }
+        }
+    };
+}
+
+float_approx_eq!(f32, i32);
+float_approx_eq!(f64, i64);
@@ -1,37 +1,276 @@
-//! Real vector-space algebra implementation using arrays over f32.
+//! Vector-space algebra implementation using arrays.
 //!
-//! Provides a default implementation of vectors, covectors and other
-//! algebraic structures that is backed by a f32 array.
+//! Provides a default implementation of vectors, covectors, linear maps
+//! (matrices) and other algebraic structures that is backed by an array of
+//! any `Scalar`. Real-valued algorithms (norms, inverses, ...) are available
+//! whenever the scalar is also `Real`, e.g. f32 or f64.
 
-use crate::math::algebra::{Covector, Vector};
-use std::cmp::PartialEq;
-use std::fmt::{Debug, Error, Formatter};
-use std::ops::{Add, Mul, Neg};
+use crate::math::algebra::{
+    gram_schmidt, Covector, CrossProduct, InnerProductSpace, LinearMap, Real, Scalar, Vector,
+};
+use crate::math::approx::ApproxEq;
+use std::array::TryFromSliceError;
+use std::cmp::{Ordering, PartialEq};
+use std::fmt::{Debug, Display, Error, Formatter};
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
 
 /// Array backed vector.
 #[derive(Clone, Copy)]
-pub struct ArrayVector<const N: usize> {
-    data: [f32; N],
+pub struct ArrayVector<F: Scalar, const N: usize> {
+    data: [F; N],
 }
 
-pub fn make_array_vector<const N: usize>(array: [f32; N]) -> ArrayVector<N> {
+pub fn make_array_vector<F: Scalar, const N: usize>(array: [F; N]) -> ArrayVector<F, N> {
     ArrayVector { data: array }
 }
 
-impl<const N: usize> Debug for ArrayVector<N> {
+impl<F: Scalar, const N: usize> ArrayVector<F, N> {
+    /// Returns the vector of all zeros.
+    pub fn zeros() -> Self {
+        ArrayVector {
+            data: [F::additive_unit(); N],
+        }
+    }
+
+    /// Returns the vector of all ones.
+    pub fn ones() -> Self {
+        ArrayVector {
+            data: [F::multiplicative_unit(); N],
+        }
+    }
+
+    /// Returns the i-th standard basis vector, i.e. the vector whose i-th
+    /// component is one and whose others are zero. Panics if i >= N.
+    pub fn basis(i: usize) -> Self {
+        let mut vector = Self::zeros();
+        vector.data[i] = F::multiplicative_unit();
+        vector
+    }
+
+    /// Returns the component at the given index.
+    pub fn get(&self, index: usize) -> F {
+        self.data[index]
+    }
+
+    /// Builds a vector by copying a slice, failing if its length is not N.
+    pub fn try_from_slice(slice: &[F]) -> Result<Self, TryFromSliceError> {
+        Ok(ArrayVector {
+            data: slice.try_into()?,
+        })
+    }
+
+    /// Returns the components as a slice.
+    pub fn as_slice(&self) -> &[F] {
+        &self.data
+    }
+
+    /// Returns the components as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [F] {
+        &mut self.data
+    }
+
+    /// Returns the first component. Panics if the vector is empty.
+    pub fn x(&self) -> F {
+        self.data[0]
+    }
+
+    /// Returns the second component. Panics if the vector has fewer than two
+    /// components.
+    pub fn y(&self) -> F {
+        self.data[1]
+    }
+
+    /// Returns the third component. Panics if the vector has fewer than three
+    /// components.
+    pub fn z(&self) -> F {
+        self.data[2]
+    }
+
+    /// Returns the fourth component, e.g. the homogeneous coordinate of a
+    /// 4-vector. Panics if the vector has fewer than four components.
+    pub fn w(&self) -> F {
+        self.data[3]
+    }
+}
+
+impl<F: Scalar, const N: usize> From<[F; N]> for ArrayVector<F, N> {
+    fn from(array: [F; N]) -> Self {
+        make_array_vector(array)
+    }
+}
+
+impl<F: Scalar, const N: usize> From<ArrayVector<F, N>> for [F; N] {
+    fn from(vector: ArrayVector<F, N>) -> Self {
+        vector.data
+    }
+}
+
+impl<F: Scalar, const N: usize> TryFrom<&[F]> for ArrayVector<F, N> {
+    type Error = TryFromSliceError;
+
+    fn try_from(slice: &[F]) -> Result<Self, Self::Error> {
+        ArrayVector::try_from_slice(slice)
+    }
+}
+
+/// Collects exactly N components into a vector. Panics if the iterator yields
+/// fewer or more than N items; use `try_from_slice` to handle that instead.
+impl<F: Scalar, const N: usize> FromIterator<F> for ArrayVector<F, N> {
+    fn from_iter<I: IntoIterator<Item = F>>(iter: I) -> Self {
+        let mut iter = iter.into_iter();
+        let mut data = [F::additive_unit(); N];
+
+        for entry in data.iter_mut() {
+            *entry = iter
+                .next()
+                .expect("Array vector expects exactly N components.");
+        }
+        assert!(
+            iter.next().is_none(),
+            "Array vector expects exactly N components."
+        );
+
+        ArrayVector { data }
+    }
+}
+
+impl<F: Scalar, const N: usize> IntoIterator for ArrayVector<F, N> {
+    type Item = F;
+    type IntoIter = std::array::IntoIter<F, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, F: Scalar, const N: usize> IntoIterator for &'a ArrayVector<F, N> {
+    type Item = &'a F;
+    type IntoIter = std::slice::Iter<'a, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl<F: Scalar, const N: usize> Index<usize> for ArrayVector<F, N> {
+    type Output = F;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+impl<F: Scalar, const N: usize> IndexMut<usize> for ArrayVector<F, N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+
+/// Serializes the vector as a tuple of its N components, e.g. a JSON array.
+#[cfg(feature = "serde")]
+impl<F: Scalar + serde::Serialize, const N: usize> serde::Serialize for ArrayVector<F, N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_array(&self.data, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, F: Scalar + serde::Deserialize<'de>, const N: usize> serde::Deserialize<'de>
+    for ArrayVector<F, N>
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(ArrayVector {
+            data: deserialize_array(deserializer, F::additive_unit())?,
+        })
+    }
+}
+
+/// Serializes a fixed-size array as a tuple; serde itself only does so for
+/// arrays of up to 32 entries.
+#[cfg(feature = "serde")]
+fn serialize_array<T: serde::Serialize, S: serde::Serializer, const N: usize>(
+    array: &[T; N],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    use serde::ser::SerializeTuple;
+
+    let mut tuple = serializer.serialize_tuple(N)?;
+    for entry in array.iter() {
+        tuple.serialize_element(entry)?;
+    }
+    tuple.end()
+}
+
+/// Deserializes a fixed-size array from a tuple of exactly N entries.
+#[cfg(feature = "serde")]
+fn deserialize_array<'de, T, D, const N: usize>(
+    deserializer: D,
+    fill: T,
+) -> Result<[T; N], D::Error>
+where
+    T: serde::Deserialize<'de> + Copy,
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::{Error as _, SeqAccess, Visitor};
+    use std::marker::PhantomData;
+
+    struct ArrayVisitor<T, const N: usize>(T, PhantomData<[T; N]>);
+
+    impl<'de, T: serde::Deserialize<'de> + Copy, const N: usize> Visitor<'de> for ArrayVisitor<T, N> {
+        type Value = [T; N];
+
+        fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "an array of {N} entries")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut array = [self.0; N];
+            for (i, entry) in array.iter_mut().enumerate() {
+                *entry = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(i, &self))?;
+            }
+            if seq.next_element::<T>()?.is_some() {
+                return Err(A::Error::invalid_length(N + 1, &self));
+            }
+            Ok(array)
+        }
+    }
+
+    deserializer.deserialize_tuple(N, ArrayVisitor(fill, PhantomData))
+}
+
+impl<F: Scalar + Debug, const N: usize> Debug for ArrayVector<F, N> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
         self.data.fmt(f)
     }
 }
 
-impl<const N: usize> Add<Self> for ArrayVector<N> {
+/// Formats the vector as `[x, y, z]`. The precision, if given (e.g. `{:.3}`),
+/// applies to every component.
+impl<F: Scalar + Display, const N: usize> Display for ArrayVector<F, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let entries: Vec<String> = self.data.iter().map(|a| format_entry(a, f)).collect();
+        write!(f, "[{}]", entries.join(", "))
+    }
+}
+
+/// Formats a single entry with the formatter's precision, if any.
+fn format_entry<F: Display>(entry: &F, f: &Formatter<'_>) -> String {
+    match f.precision() {
+        Some(precision) => format!("{entry:.precision$}"),
+        None => format!("{entry}"),
+    }
+}
+
+impl<F: Scalar, const N: usize> Add<Self> for ArrayVector<F, N> {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        let mut new_data: [f32; N] = self.data;
+        let mut new_data: [F; N] = self.data;
 
-        for n in 0..N {
-            new_data[n] = new_data[n] + rhs.data[n];
+        for (lhs, rhs) in new_data.iter_mut().zip(rhs.data.iter()) {
+            *lhs = *lhs + *rhs;
         }
 
         // I'd prefer to do this functionally, but we cannot collect into an
@@ -40,7 +279,7 @@ impl<const N: usize> Add<Self> for ArrayVector<N> {
     }
 }
 
-impl<const N: usize> Neg for ArrayVector<N> {
+impl<F: Scalar, const N: usize> Neg for ArrayVector<F, N> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
@@ -50,36 +289,926 @@ impl<const N: usize> Neg for ArrayVector<N> {
     }
 }
 
+impl<F: Scalar, const N: usize> AddAssign for ArrayVector<F, N> {
+    fn add_assign(&mut self, rhs: Self) {
+        for (lhs, rhs) in self.data.iter_mut().zip(rhs.data.iter()) {
+            *lhs = *lhs + *rhs;
+        }
+    }
+}
+
+impl<F: Scalar, const N: usize> Sub<Self> for ArrayVector<F, N> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<F: Scalar, const N: usize> SubAssign for ArrayVector<F, N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        for (lhs, rhs) in self.data.iter_mut().zip(rhs.data.iter()) {
+            *lhs = *lhs + -*rhs;
+        }
+    }
+}
+
 /// Scalar multiplication for array-backed vector.
-impl<const N: usize> Mul<f32> for ArrayVector<N> {
+impl<F: Scalar, const N: usize> Mul<F> for ArrayVector<F, N> {
     type Output = Self;
 
-    fn mul(self, rhs: f32) -> Self::Output {
+    fn mul(self, rhs: F) -> Self::Output {
         ArrayVector {
             data: self.data.map(|a| a * rhs),
         }
     }
 }
 
+/// In-place scalar multiplication for array-backed vector.
+impl<F: Scalar, const N: usize> MulAssign<F> for ArrayVector<F, N> {
+    fn mul_assign(&mut self, rhs: F) {
+        for lhs in self.data.iter_mut() {
+            *lhs = *lhs * rhs;
+        }
+    }
+}
+
 /// Vector multiplication for array-backed vector. Used by covector.
-impl<const N: usize> Mul<ArrayVector<N>> for ArrayVector<N> {
-    type Output = f32;
+impl<F: Scalar, const N: usize> Mul<ArrayVector<F, N>> for ArrayVector<F, N> {
+    type Output = F;
 
-    fn mul(self, rhs: ArrayVector<N>) -> Self::Output {
+    fn mul(self, rhs: ArrayVector<F, N>) -> Self::Output {
         self.data
             .iter()
             .zip(rhs.data.iter())
-            .map(|(a, b)| a * b)
-            .fold(0.0, |a, b| a + b)
+            .map(|(a, b)| *a * *b)
+            .fold(F::additive_unit(), |a, b| a + b)
     }
 }
 
-impl<const N: usize> PartialEq for ArrayVector<N> {
+impl<F: Scalar, const N: usize> PartialEq for ArrayVector<F, N> {
     fn eq(&self, other: &Self) -> bool {
         self.data == other.data
     }
 }
 
-impl<const N: usize> Vector<f32> for ArrayVector<N> {}
+/// Component-wise approximate equality.
+impl<F: Scalar + ApproxEq<Tolerance = F>, const N: usize> ApproxEq for ArrayVector<F, N> {
+    type Tolerance = F;
+
+    fn default_epsilon() -> F {
+        F::default_epsilon()
+    }
+
+    fn default_max_relative() -> F {
+        F::default_max_relative()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: F) -> bool {
+        self.data
+            .iter()
+            .zip(other.data.iter())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: F, max_relative: F) -> bool {
+        self.data
+            .iter()
+            .zip(other.data.iter())
+            .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: F, max_ulps: u32) -> bool {
+        self.data
+            .iter()
+            .zip(other.data.iter())
+            .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+impl<F: Scalar, const N: usize> Vector<F> for ArrayVector<F, N> {}
+
+impl<F: Scalar, const N: usize> Covector<F, ArrayVector<F, N>> for ArrayVector<F, N> {}
+
+/// Inherent forms of the `InnerProductSpace` methods most often needed, so
+/// they can be called without importing the trait.
+impl<F: Real, const N: usize> ArrayVector<F, N> {
+    /// Returns the Euclidean length of the vector.
+    pub fn norm(self) -> F {
+        InnerProductSpace::norm(self)
+    }
+
+    /// Returns the vector scaled to unit length, or `None` for the zero
+    /// vector.
+    pub fn normalize(self) -> Option<Self> {
+        InnerProductSpace::normalize(self)
+    }
+}
+
+impl<F: Real, const N: usize> InnerProductSpace<F> for ArrayVector<F, N> {
+    fn dot(self, rhs: Self) -> F {
+        self * rhs
+    }
+
+    fn norm(self) -> F {
+        self.squared_norm().sqrt()
+    }
+
+    fn normalize(self) -> Option<Self> {
+        let norm = self.norm();
+
+        if norm == F::additive_unit() {
+            None
+        } else {
+            Some(self * (F::multiplicative_unit() / norm))
+        }
+    }
+}
+
+impl<F: Scalar> CrossProduct<F> for ArrayVector<F, 3> {
+    fn cross(self, rhs: Self) -> Self {
+        let [a1, a2, a3] = self.data;
+        let [b1, b2, b3] = rhs.data;
+
+        ArrayVector {
+            data: [
+                a2 * b3 + -(a3 * b2),
+                a3 * b1 + -(a1 * b3),
+                a1 * b2 + -(a2 * b1),
+            ],
+        }
+    }
+}
+
+/// Cross product of homogeneous vectors. The last component is ignored and the
+/// result is a direction, i.e. its last component is zero.
+impl<F: Scalar> CrossProduct<F> for ArrayVector<F, 4> {
+    fn cross(self, rhs: Self) -> Self {
+        let [a1, a2, a3, _] = self.data;
+        let [b1, b2, b3, _] = rhs.data;
+        let [c1, c2, c3] = make_array_vector([a1, a2, a3])
+            .cross(make_array_vector([b1, b2, b3]))
+            .data;
+
+        ArrayVector {
+            data: [c1, c2, c3, F::additive_unit()],
+        }
+    }
+}
+
+/// Array backed matrix with R rows and C columns, stored row-major.
+#[derive(Clone, Copy)]
+pub struct ArrayMatrix<F: Scalar, const R: usize, const C: usize> {
+    data: [[F; C]; R],
+}
+
+/// Builds a matrix from its rows.
+pub fn make_array_matrix<F: Scalar, const R: usize, const C: usize>(
+    rows: [[F; C]; R],
+) -> ArrayMatrix<F, R, C> {
+    ArrayMatrix { data: rows }
+}
+
+/// Returns the outer product v w^T, i.e. the matrix whose entry (i, j) is
+/// v_i w_j.
+pub fn outer<F: Scalar, const N: usize, const M: usize>(
+    v: ArrayVector<F, N>,
+    w: ArrayVector<F, M>,
+) -> ArrayMatrix<F, N, M> {
+    ArrayMatrix {
+        data: v.data.map(|a| w.data.map(|b| a * b)),
+    }
+}
+
+/// Returns the concatenation [a; b] of two vectors. The result's length N
+/// must equal N1 + N2, which is checked at runtime.
+pub fn concatenate<F: Scalar, const N1: usize, const N2: usize, const N: usize>(
+    a: ArrayVector<F, N1>,
+    b: ArrayVector<F, N2>,
+) -> ArrayVector<F, N> {
+    assert!(
+        N1 + N2 == N,
+        "Concatenated vector must have N1 + N2 components."
+    );
+
+    ArrayVector {
+        data: std::array::from_fn(|i| if i < N1 { a.data[i] } else { b.data[i - N1] }),
+    }
+}
+
+impl<F: Scalar, const R: usize, const C: usize> ArrayMatrix<F, R, C> {
+    /// Returns the matrix of all zeros.
+    pub fn zero() -> Self {
+        ArrayMatrix {
+            data: [[F::additive_unit(); C]; R],
+        }
+    }
+
+    /// Returns the entry at the given row and column.
+    pub fn get(&self, row: usize, column: usize) -> F {
+        self.data[row][column]
+    }
+
+    /// Returns the BR x BC block whose top-left entry is at (row, column).
+    /// Panics if the block does not fit inside the matrix.
+    pub fn block<const BR: usize, const BC: usize>(
+        &self,
+        row: usize,
+        column: usize,
+    ) -> ArrayMatrix<F, BR, BC> {
+        assert!(
+            row + BR <= R && column + BC <= C,
+            "Array matrix block must lie inside the matrix."
+        );
+
+        ArrayMatrix {
+            data: std::array::from_fn(|i| std::array::from_fn(|j| self.data[row + i][column + j])),
+        }
+    }
+
+    /// Overwrites the block whose top-left entry is at (row, column) with the
+    /// given matrix. Panics if the block does not fit inside the matrix.
+    pub fn set_block<const BR: usize, const BC: usize>(
+        &mut self,
+        row: usize,
+        column: usize,
+        block: &ArrayMatrix<F, BR, BC>,
+    ) {
+        assert!(
+            row + BR <= R && column + BC <= C,
+            "Array matrix block must lie inside the matrix."
+        );
 
-impl<const N: usize> Covector<f32, ArrayVector<N>> for ArrayVector<N> {}
+        for (target, source) in self.data[row..row + BR].iter_mut().zip(block.data.iter()) {
+            target[column..column + BC].copy_from_slice(source);
+        }
+    }
+
+    /// Returns the given row as a vector.
+    pub fn row(&self, row: usize) -> ArrayVector<F, C> {
+        ArrayVector {
+            data: self.data[row],
+        }
+    }
+
+    /// Returns the given column as a vector.
+    pub fn column(&self, column: usize) -> ArrayVector<F, R> {
+        ArrayVector {
+            data: self.data.map(|row| row[column]),
+        }
+    }
+
+    /// Returns the matrix [self other], i.e. `other`'s columns appended to
+    /// this matrix's. The result's column count C2 must equal C + C1, which is
+    /// checked at runtime as stable Rust cannot express it in the type.
+    pub fn hstack<const C1: usize, const C2: usize>(
+        &self,
+        other: &ArrayMatrix<F, R, C1>,
+    ) -> ArrayMatrix<F, R, C2> {
+        assert!(C + C1 == C2, "Stacked matrix must have C + C1 columns.");
+
+        let mut result = ArrayMatrix::zero();
+        result.set_block(0, 0, self);
+        result.set_block(0, C, other);
+        result
+    }
+
+    /// Returns the matrix [self; other], i.e. `other`'s rows appended to this
+    /// matrix's. The result's row count R2 must equal R + R1, which is checked
+    /// at runtime as stable Rust cannot express it in the type.
+    pub fn vstack<const R1: usize, const R2: usize>(
+        &self,
+        other: &ArrayMatrix<F, R1, C>,
+    ) -> ArrayMatrix<F, R2, C> {
+        assert!(R + R1 == R2, "Stacked matrix must have R + R1 rows.");
+
+        let mut result = ArrayMatrix::zero();
+        result.set_block(0, 0, self);
+        result.set_block(R, 0, other);
+        result
+    }
+
+    /// Builds a matrix from its columns.
+    pub fn from_columns(columns: [ArrayVector<F, R>; C]) -> Self {
+        ArrayMatrix {
+            data: std::array::from_fn(|i| columns.map(|column| column.data[i])),
+        }
+    }
+
+    /// Builds a matrix from its rows.
+    pub fn from_rows(rows: [ArrayVector<F, C>; R]) -> Self {
+        ArrayMatrix {
+            data: rows.map(|row| row.data),
+        }
+    }
+
+    /// Returns the matrix with rows and columns exchanged.
+    pub fn transpose(&self) -> ArrayMatrix<F, C, R> {
+        let mut data = [[F::additive_unit(); R]; C];
+
+        for (i, row) in self.data.iter().enumerate() {
+            for (j, entry) in row.iter().enumerate() {
+                data[j][i] = *entry;
+            }
+        }
+
+        ArrayMatrix { data }
+    }
+}
+
+impl<F: Scalar, const N: usize> ArrayMatrix<F, N, N> {
+    /// Returns the (square) identity matrix.
+    pub fn identity() -> Self {
+        let mut data = [[F::additive_unit(); N]; N];
+
+        for (i, row) in data.iter_mut().enumerate() {
+            row[i] = F::multiplicative_unit();
+        }
+
+        ArrayMatrix { data }
+    }
+}
+
+impl<F: Real, const N: usize> ArrayMatrix<F, N, N> {
+    /// Returns the determinant of the matrix.
+    ///
+    /// Uses closed-form expansions up to 4x4 and Gaussian elimination with
+    /// partial pivoting for larger matrices.
+    pub fn determinant(&self) -> F {
+        let m = &self.data;
+
+        match N {
+            0 => F::multiplicative_unit(),
+            1 => m[0][0],
+            2 => m[0][0] * m[1][1] - m[0][1] * m[1][0],
+            3 => {
+                m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+                    - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+                    + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+            }
+            4 => {
+                let (s, c) = self.minors_4x4();
+                s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+            }
+            _ => {
+                let mut lu = self.data;
+                let mut determinant = F::multiplicative_unit();
+
+                for column in 0..N {
+                    let pivot = Self::pivot(&lu, column);
+
+                    if lu[pivot][column] == F::additive_unit() {
+                        return F::additive_unit();
+                    }
+                    if pivot != column {
+                        lu.swap(pivot, column);
+                        determinant = -determinant;
+                    }
+
+                    determinant = determinant * lu[column][column];
+                    let pivot_row = lu[column];
+                    for row in lu.iter_mut().skip(column + 1) {
+                        let factor = row[column] / pivot_row[column];
+                        for (entry, pivot_entry) in row.iter_mut().zip(pivot_row).skip(column) {
+                            *entry = *entry - factor * pivot_entry;
+                        }
+                    }
+                }
+
+                determinant
+            }
+        }
+    }
+
+    /// Returns the inverse of the matrix, or `None` if it is singular (to
+    /// within floating-point precision relative to its largest entry), i.e. if
+    /// its determinant is at most N * epsilon * scale^N, where scale is the
+    /// largest entry's magnitude.
+    ///
+    /// Uses the adjugate up to 4x4 and Gauss-Jordan elimination with partial
+    /// pivoting for larger matrices.
+    pub fn inverse(&self) -> Option<Self> {
+        let m = &self.data;
+        let zero = F::additive_unit();
+        let scale = m
+            .iter()
+            .flatten()
+            .fold(zero, |a, b| if b.abs() > a { b.abs() } else { a });
+        let size = (0..N).fold(zero, |a, _| a + F::multiplicative_unit());
+        let tolerance = size * F::epsilon() * scale;
+
+        if N > 4 {
+            return self.gauss_jordan_inverse(tolerance);
+        }
+
+        // Rounding alone leaves a determinant of about this size, even for an
+        // exactly singular matrix.
+        let singular_determinant = (1..N).fold(tolerance, |a, _| a * scale);
+        let determinant = self.determinant();
+        if determinant.abs() <= singular_determinant || scale == zero {
+            return None;
+        }
+
+        let mut adjugate = [[zero; N]; N];
+        match N {
+            1 => adjugate[0][0] = F::multiplicative_unit(),
+            2 => {
+                adjugate[0][0] = m[1][1];
+                adjugate[0][1] = -m[0][1];
+                adjugate[1][0] = -m[1][0];
+                adjugate[1][1] = m[0][0];
+            }
+            3 => {
+                for (i, row) in adjugate.iter_mut().enumerate() {
+                    for (j, entry) in row.iter_mut().enumerate() {
+                        // Cofactor of (j, i), using cyclic indices to fold in the sign.
+                        let (r0, r1) = ((j + 1) % 3, (j + 2) % 3);
+                        let (c0, c1) = ((i + 1) % 3, (i + 2) % 3);
+                        *entry = m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0];
+                    }
+                }
+            }
+            4 => {
+                let (s, c) = self.minors_4x4();
+                let a = [
+                    [
+                        m[1][1] * c[5] - m[1][2] * c[4] + m[1][3] * c[3],
+                        -m[0][1] * c[5] + m[0][2] * c[4] - m[0][3] * c[3],
+                        m[3][1] * s[5] - m[3][2] * s[4] + m[3][3] * s[3],
+                        -m[2][1] * s[5] + m[2][2] * s[4] - m[2][3] * s[3],
+                    ],
+                    [
+                        -m[1][0] * c[5] + m[1][2] * c[2] - m[1][3] * c[1],
+                        m[0][0] * c[5] - m[0][2] * c[2] + m[0][3] * c[1],
+                        -m[3][0] * s[5] + m[3][2] * s[2] - m[3][3] * s[1],
+                        m[2][0] * s[5] - m[2][2] * s[2] + m[2][3] * s[1],
+                    ],
+                    [
+                        m[1][0] * c[4] - m[1][1] * c[2] + m[1][3] * c[0],
+                        -m[0][0] * c[4] + m[0][1] * c[2] - m[0][3] * c[0],
+                        m[3][0] * s[4] - m[3][1] * s[2] + m[3][3] * s[0],
+                        -m[2][0] * s[4] + m[2][1] * s[2] - m[2][3] * s[0],
+                    ],
+                    [
+                        -m[1][0] * c[3] + m[1][1] * c[1] - m[1][2] * c[0],
+                        m[0][0] * c[3] - m[0][1] * c[1] + m[0][2] * c[0],
+                        -m[3][0] * s[3] + m[3][1] * s[1] - m[3][2] * s[0],
+                        m[2][0] * s[3] - m[2][1] * s[1] + m[2][2] * s[0],
+                    ],
+                ];
+                for (i, row) in adjugate.iter_mut().enumerate() {
+                    row.copy_from_slice(&a[i]);
+                }
+            }
+            _ => {}
+        }
+
+        Some(ArrayMatrix {
+            data: adjugate.map(|row| row.map(|entry| entry / determinant)),
+        })
+    }
+
+    /// Returns the 2x2 minors of the top two rows (s) and bottom two rows (c)
+    /// of a 4x4 matrix, over column pairs (0,1), (0,2), (0,3), (1,2), (1,3),
+    /// (2,3).
+    fn minors_4x4(&self) -> ([F; 6], [F; 6]) {
+        let m = &self.data;
+        let pairs = [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)];
+        let minor = |r0: usize, r1: usize| {
+            pairs.map(|(c0, c1)| m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0])
+        };
+
+        (minor(0, 1), minor(2, 3))
+    }
+
+    /// Returns the row, at or below the given one, whose entry in the given
+    /// column has the largest magnitude.
+    fn pivot(rows: &[[F; N]; N], column: usize) -> usize {
+        (column..N)
+            .max_by(|a, b| {
+                rows[*a][column]
+                    .abs()
+                    .partial_cmp(&rows[*b][column].abs())
+                    .unwrap_or(Ordering::Equal)
+            })
+            .unwrap()
+    }
+
+    fn gauss_jordan_inverse(&self, tolerance: F) -> Option<Self> {
+        let mut left = self.data;
+        let mut right = Self::identity().data;
+
+        for column in 0..N {
+            let pivot = Self::pivot(&left, column);
+
+            if left[pivot][column].abs() <= tolerance {
+                return None;
+            }
+            left.swap(pivot, column);
+            right.swap(pivot, column);
+
+            let inverse_pivot = F::multiplicative_unit() / left[column][column];
+            let pivot_left = left[column].map(|entry| entry * inverse_pivot);
+            let pivot_right = right[column].map(|entry| entry * inverse_pivot);
+
+            for (row, (left_row, right_row)) in left.iter_mut().zip(right.iter_mut()).enumerate() {
+                if row == column {
+                    *left_row = pivot_left;
+                    *right_row = pivot_right;
+                    continue;
+                }
+                let factor = left_row[column];
+                for (entry, pivot_entry) in left_row.iter_mut().zip(pivot_left) {
+                    *entry = *entry - factor * pivot_entry;
+                }
+                for (entry, pivot_entry) in right_row.iter_mut().zip(pivot_right) {
+                    *entry = *entry - factor * pivot_entry;
+                }
+            }
+        }
+
+        Some(ArrayMatrix { data: right })
+    }
+}
+
+/// Singular value decomposition A = U diag(singular_values) V^T of an R x C
+/// matrix.
+///
+/// The singular values are non-negative and sorted in decreasing order. The
+/// columns of V are orthonormal, as are the columns of U that correspond to
+/// non-zero singular values; the columns of U for zero singular values (of
+/// which there are at least C - R when R < C) are zero.
+#[derive(Clone, Copy, Debug)]
+pub struct SingularValueDecomposition<F: Scalar, const R: usize, const C: usize> {
+    pub u: ArrayMatrix<F, R, C>,
+    pub singular_values: ArrayVector<F, C>,
+    pub v: ArrayMatrix<F, C, C>,
+}
+
+/// Upper bound on the number of Jacobi sweeps, which in practice converge in
+/// well under ten for small matrices.
+const MAX_JACOBI_SWEEPS: usize = 64;
+
+impl<F: Real, const R: usize, const C: usize> ArrayMatrix<F, R, C> {
+    /// Returns the singular value decomposition of the matrix.
+    ///
+    /// Uses one-sided Jacobi rotations, which are accurate even for small
+    /// singular values and straightforward for the small matrices (e.g.
+    /// manipulator Jacobians) this type is meant for.
+    pub fn svd(&self) -> SingularValueDecomposition<F, R, C> {
+        let zero = F::additive_unit();
+        let one = F::multiplicative_unit();
+        let two = one + one;
+        // Rows of `columns` are the columns of A V; rows of `v` are the columns
+        // of V.
+        let mut columns = self.transpose().data;
+        let mut v = ArrayMatrix::<F, C, C>::identity().data;
+        let dot = |a: &[F; R], b: &[F; R]| {
+            a.iter()
+                .zip(b.iter())
+                .fold(zero, |sum, (x, y)| sum + *x * *y)
+        };
+
+        for _ in 0..MAX_JACOBI_SWEEPS {
+            let mut rotated = false;
+
+            for p in 0..C {
+                for q in (p + 1)..C {
+                    let alpha = dot(&columns[p], &columns[p]);
+                    let beta = dot(&columns[q], &columns[q]);
+                    let gamma = dot(&columns[p], &columns[q]);
+
+                    if gamma.abs() <= F::epsilon() * (alpha * beta).sqrt() || gamma == zero {
+                        continue;
+                    }
+                    rotated = true;
+
+                    // Rotation by the angle that makes columns p and q orthogonal.
+                    let zeta = (beta - alpha) / (two * gamma);
+                    let t = one / (zeta.abs() + (one + zeta * zeta).sqrt());
+                    let t = if zeta < zero { -t } else { t };
+                    let c = one / (one + t * t).sqrt();
+                    let s = c * t;
+
+                    let rotate = |a: &mut F, b: &mut F| {
+                        let (x, y) = (*a, *b);
+                        *a = c * x - s * y;
+                        *b = s * x + c * y;
+                    };
+                    let (head, tail) = columns.split_at_mut(q);
+                    for (a, b) in head[p].iter_mut().zip(tail[0].iter_mut()) {
+                        rotate(a, b);
+                    }
+                    let (head, tail) = v.split_at_mut(q);
+                    for (a, b) in head[p].iter_mut().zip(tail[0].iter_mut()) {
+                        rotate(a, b);
+                    }
+                }
+            }
+
+            if !rotated {
+                break;
+            }
+        }
+
+        let mut order: [usize; C] = std::array::from_fn(|i| i);
+        let norms = columns.map(|column| dot(&column, &column).sqrt());
+        order.sort_by(|a, b| norms[*b].partial_cmp(&norms[*a]).unwrap_or(Ordering::Equal));
+
+        let scale = norms.iter().fold(zero, |a, b| if *b > a { *b } else { a });
+        let mut u = [[zero; C]; R];
+        let mut v_sorted = [[zero; C]; C];
+        for (j, index) in order.iter().enumerate() {
+            let norm = norms[*index];
+            for i in 0..C {
+                v_sorted[i][j] = v[*index][i];
+            }
+            if norm > F::epsilon() * scale && norm > zero {
+                for i in 0..R {
+                    u[i][j] = columns[*index][i] / norm;
+                }
+            }
+        }
+
+        SingularValueDecomposition {
+            u: ArrayMatrix { data: u },
+            singular_values: ArrayVector {
+                data: order.map(|index| norms[index]),
+            },
+            v: ArrayMatrix { data: v_sorted },
+        }
+    }
+
+    /// Returns the Moore-Penrose pseudo-inverse of the matrix, treating
+    /// singular values below max(R, C) * epsilon * (largest singular value) as
+    /// zero.
+    pub fn pinv(&self) -> ArrayMatrix<F, C, R> {
+        let svd = self.svd();
+        let largest = svd.singular_values.data.first().copied();
+        let size = (0..R.max(C)).fold(F::additive_unit(), |a, _| a + F::multiplicative_unit());
+        let tolerance = size * F::epsilon() * largest.unwrap_or(F::additive_unit());
+
+        svd.pinv_with_tolerance(tolerance)
+    }
+}
+
+impl<F: Real, const R: usize, const C: usize> SingularValueDecomposition<F, R, C> {
+    /// Returns the pseudo-inverse V diag(1 / singular_values) U^T, treating
+    /// singular values at or below the tolerance as zero.
+    pub fn pinv_with_tolerance(&self, tolerance: F) -> ArrayMatrix<F, C, R> {
+        let zero = F::additive_unit();
+        let mut data = [[zero; R]; C];
+
+        for (k, sigma) in self.singular_values.data.iter().enumerate() {
+            if *sigma <= tolerance || *sigma == zero {
+                continue;
+            }
+            for (i, row) in data.iter_mut().enumerate() {
+                let scaled = self.v.data[i][k] / *sigma;
+                for (j, entry) in row.iter_mut().enumerate() {
+                    *entry = *entry + scaled * self.u.data[j][k];
+                }
+            }
+        }
+
+        ArrayMatrix { data }
+    }
+
+    /// Returns the number of singular values above the tolerance.
+    pub fn rank(&self, tolerance: F) -> usize {
+        self.singular_values
+            .data
+            .iter()
+            .filter(|sigma| **sigma > tolerance)
+            .count()
+    }
+}
+
+/// Eigen-decomposition A = V diag(eigenvalues) V^T of a symmetric matrix.
+///
+/// The eigenvalues are sorted in decreasing order and the columns of V are
+/// the corresponding orthonormal eigenvectors.
+#[derive(Clone, Copy, Debug)]
+pub struct SymmetricEigenDecomposition<F: Scalar, const N: usize> {
+    pub eigenvalues: ArrayVector<F, N>,
+    pub eigenvectors: ArrayMatrix<F, N, N>,
+}
+
+impl<F: Real, const N: usize> ArrayMatrix<F, N, N> {
+    /// Returns the nearest orthonormal matrix in the Gram-Schmidt sense: the
+    /// first column is normalized and each later column is made orthogonal to
+    /// those before it. Useful to remove the drift in a rotation matrix after
+    /// repeated composition. Returns `None` if the columns are (numerically)
+    /// linearly dependent.
+    pub fn orthonormalize(&self) -> Option<Self> {
+        let columns: Vec<ArrayVector<F, N>> = self
+            .transpose()
+            .data
+            .iter()
+            .map(|column| make_array_vector(*column))
+            .collect();
+        let basis = gram_schmidt(&columns)?;
+
+        Some(ArrayMatrix {
+            data: std::array::from_fn(|i| std::array::from_fn(|j| basis[j].data[i])),
+        })
+    }
+
+    /// Returns the eigen-decomposition of a symmetric matrix, e.g. an inertia
+    /// tensor or the manipulability matrix J J^T.
+    ///
+    /// Uses cyclic Jacobi rotations. Only the upper triangle is read; the
+    /// matrix is assumed to be symmetric.
+    pub fn symmetric_eigen(&self) -> SymmetricEigenDecomposition<F, N> {
+        let zero = F::additive_unit();
+        let one = F::multiplicative_unit();
+        let two = one + one;
+        let mut a: [[F; N]; N] =
+            std::array::from_fn(|i| std::array::from_fn(|j| self.data[i.min(j)][i.max(j)]));
+        let mut v = Self::identity().data;
+
+        for _ in 0..MAX_JACOBI_SWEEPS {
+            let squares = |diagonal: bool| {
+                let entries = a.iter().enumerate().flat_map(|(i, row)| {
+                    row.iter()
+                        .enumerate()
+                        .filter(move |(j, _)| (i == *j) == diagonal)
+                });
+                entries.fold(zero, |sum, (_, x)| sum + *x * *x)
+            };
+            let off_diagonal = squares(false);
+            if off_diagonal <= F::epsilon() * F::epsilon() * squares(true) {
+                break;
+            }
+
+            for p in 0..N {
+                for q in (p + 1)..N {
+                    if a[p][q] == zero {
+                        continue;
+                    }
+
+                    // Rotation by the angle that zeroes entries (p, q) and (q, p).
+                    let theta = (a[q][q] - a[p][p]) / (two * a[p][q]);
+                    let t = one / (theta.abs() + (theta * theta + one).sqrt());
+                    let t = if theta < zero { -t } else { t };
+                    let c = one / (t * t + one).sqrt();
+                    let s = t * c;
+
+                    for row in a.iter_mut().chain(v.iter_mut()) {
+                        let (x, y) = (row[p], row[q]);
+                        row[p] = c * x - s * y;
+                        row[q] = s * x + c * y;
+                    }
+                    let (row_p, row_q) = (a[p], a[q]);
+                    for k in 0..N {
+                        a[p][k] = c * row_p[k] - s * row_q[k];
+                        a[q][k] = s * row_p[k] + c * row_q[k];
+                    }
+                }
+            }
+        }
+
+        let mut order: [usize; N] = std::array::from_fn(|i| i);
+        order.sort_by(|x, y| a[*y][*y].partial_cmp(&a[*x][*x]).unwrap_or(Ordering::Equal));
+
+        SymmetricEigenDecomposition {
+            eigenvalues: ArrayVector {
+                data: order.map(|index| a[index][index]),
+            },
+            eigenvectors: ArrayMatrix {
+                data: v.map(|row| order.map(|index| row[index])),
+            },
+        }
+    }
+}
+
+/// Serializes the matrix as a tuple of its R rows, each a tuple of C entries.
+#[cfg(feature = "serde")]
+impl<F: Scalar + serde::Serialize, const R: usize, const C: usize> serde::Serialize
+    for ArrayMatrix<F, R, C>
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_array(&self.data.map(|row| ArrayVector { data: row }), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, F: Scalar + serde::Deserialize<'de>, const R: usize, const C: usize>
+    serde::Deserialize<'de> for ArrayMatrix<F, R, C>
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let rows: [ArrayVector<F, C>; R] = deserialize_array(deserializer, ArrayVector::zeros())?;
+        Ok(ArrayMatrix {
+            data: rows.map(|row| row.data),
+        })
+    }
+}
+
+impl<F: Scalar + Debug, const R: usize, const C: usize> Debug for ArrayMatrix<F, R, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        self.data.fmt(f)
+    }
+}
+
+/// Formats the matrix one row per line, with the columns right-aligned. The
+/// precision, if given (e.g. `{:.3}`), applies to every entry.
+impl<F: Scalar + Display, const R: usize, const C: usize> Display for ArrayMatrix<F, R, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let entries: Vec<Vec<String>> = self
+            .data
+            .iter()
+            .map(|row| row.iter().map(|a| format_entry(a, f)).collect())
+            .collect();
+        let width = entries.iter().flatten().map(|a| a.len()).max().unwrap_or(0);
+
+        for (i, row) in entries.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            let row: Vec<String> = row.iter().map(|a| format!("{a:>width$}")).collect();
+            write!(f, "[{}]", row.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Entry-wise approximate equality.
+impl<F: Scalar + ApproxEq<Tolerance = F>, const R: usize, const C: usize> ApproxEq
+    for ArrayMatrix<F, R, C>
+{
+    type Tolerance = F;
+
+    fn default_epsilon() -> F {
+        F::default_epsilon()
+    }
+
+    fn default_max_relative() -> F {
+        F::default_max_relative()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: F) -> bool {
+        self.data
+            .iter()
+            .flatten()
+            .zip(other.data.iter().flatten())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: F, max_relative: F) -> bool {
+        self.data
+            .iter()
+            .flatten()
+            .zip(other.data.iter().flatten())
+            .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: F, max_ulps: u32) -> bool {
+        self.data
+            .iter()
+            .flatten()
+            .zip(other.data.iter().flatten())
+            .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+impl<F: Scalar, const R: usize, const C: usize> PartialEq for ArrayMatrix<F, R, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+/// Matrix-vector multiplication for array-backed matrix.
+impl<F: Scalar, const R: usize, const C: usize> Mul<ArrayVector<F, C>> for ArrayMatrix<F, R, C> {
+    type Output = ArrayVector<F, R>;
+
+    fn mul(self, rhs: ArrayVector<F, C>) -> Self::Output {
+        ArrayVector {
+            data: self.data.map(|row| make_array_vector(row) * rhs),
+        }
+    }
+}
+
+/// Matrix-matrix multiplication for array-backed matrix.
+impl<F: Scalar, const R: usize, const K: usize, const C: usize> Mul<ArrayMatrix<F, K, C>>
+    for ArrayMatrix<F, R, K>
+{
+    type Output = ArrayMatrix<F, R, C>;
+
+    fn mul(self, rhs: ArrayMatrix<F, K, C>) -> Self::Output {
+        let columns = rhs.transpose();
+
+        ArrayMatrix {
+            data: self.data.map(|row| {
+                columns
+                    .data
+                    .map(|column| make_array_vector(row) * make_array_vector(column))
+            }),
+        }
+    }
+}
+
+impl<F: Scalar, const R: usize, const C: usize> LinearMap<F, ArrayVector<F, C>, ArrayVector<F, R>>
+    for ArrayMatrix<F, R, C>
+{
+}
@@ -0,0 +1,174 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+//! Dual number module.
+//!
+//! Dual numbers a + b e, with e^2 = 0, carry a value together with its
+//! derivative. Evaluating any algorithm written against the algebra traits
+//! with `Dual` scalars in place of f32 or f64 therefore performs forward-mode
+//! automatic differentiation: the result's dual part is the exact derivative
+//! of the computation with respect to whichever input was seeded with a dual
+//! part of one.
+
+use crate::math::algebra::{Real, Scalar};
+use std::cmp::Ordering;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Dual number `real + dual e` over the real field `F`.
+///
+/// Equality compares both parts, so two duals with the same value but
+/// different derivatives differ; use `real_cmp` to compare values alone.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Dual<F: Real> {
+    pub real: F,
+    pub dual: F,
+}
+
+impl<F: Real> Dual<F> {
+    pub fn new(real: F, dual: F) -> Self {
+        Dual { real, dual }
+    }
+
+    /// Builds a constant, i.e. a value whose derivative is zero.
+    pub fn constant(real: F) -> Self {
+        Dual::new(real, F::additive_unit())
+    }
+
+    /// Builds the independent variable, i.e. a value whose derivative is one.
+    pub fn variable(real: F) -> Self {
+        Dual::new(real, F::multiplicative_unit())
+    }
+
+    /// Compares the real parts alone, e.g. to choose a branch exactly as it
+    /// would be chosen for the plain values.
+    pub fn real_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.real.partial_cmp(&other.real)
+    }
+}
+
+/// Returns the value and derivative of `f` at `x`.
+pub fn derivative<F: Real>(f: impl Fn(Dual<F>) -> Dual<F>, x: F) -> (F, F) {
+    let y = f(Dual::variable(x));
+    (y.real, y.dual)
+}
+
+impl<F: Real> Add for Dual<F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Dual::new(self.real + rhs.real, self.dual + rhs.dual)
+    }
+}
+
+impl<F: Real> Sub for Dual<F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Dual::new(self.real - rhs.real, self.dual - rhs.dual)
+    }
+}
+
+impl<F: Real> Neg for Dual<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Dual::new(-self.real, -self.dual)
+    }
+}
+
+impl<F: Real> Mul for Dual<F> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Dual::new(
+            self.real * rhs.real,
+            self.real * rhs.dual + self.dual * rhs.real,
+        )
+    }
+}
+
+impl<F: Real> Div for Dual<F> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Dual::new(
+            self.real / rhs.real,
+            (self.dual * rhs.real - self.real * rhs.dual) / (rhs.real * rhs.real),
+        )
+    }
+}
+
+/// Dual numbers are ordered by their real parts, and by their dual parts when
+/// the real parts tie, which keeps the order consistent with equality. Thus
+/// comparisons made by an algorithm (e.g. pivot selection) follow the same
+/// branches as they would on the plain values unless those values tie.
+impl<F: Real> PartialOrd for Dual<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.real_cmp(other)? {
+            Ordering::Equal => self.dual.partial_cmp(&other.dual),
+            ordering => Some(ordering),
+        }
+    }
+}
+
+impl<F: Real> Scalar for Dual<F> {
+    fn additive_unit() -> Self {
+        Dual::constant(F::additive_unit())
+    }
+
+    fn multiplicative_unit() -> Self {
+        Dual::constant(F::multiplicative_unit())
+    }
+
+    fn additive_inverse(self) -> Self {
+        -self
+    }
+}
+
+impl<F: Real> Real for Dual<F> {
+    fn abs(self) -> Self {
+        if self.real < F::additive_unit() {
+            -self
+        } else {
+            self
+        }
+    }
+
+    /// The derivative is unbounded at zero, where the dual part is infinite
+    /// or undefined.
+    fn sqrt(self) -> Self {
+        let root = self.real.sqrt();
+        let two = F::multiplicative_unit() + F::multiplicative_unit();
+        Dual::new(root, self.dual / (two * root))
+    }
+
+    fn epsilon() -> Self {
+        Dual::constant(F::epsilon())
+    }
+}
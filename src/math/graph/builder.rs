@@ -0,0 +1,177 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+//! Graph Builder module.
+//!
+//! Provides a fluent builder that accumulates vertices and edges, referring to
+//! vertices by caller-chosen keys, and validates them all at once on build.
+
+use crate::math::graph::mutators::{GraphEdgeAdditionMutator, GraphVertexAdditionMutator};
+use crate::math::graph::*;
+
+/// Graph Build Failures.
+#[derive(Debug, PartialEq)]
+pub enum GraphBuildFailure<Key, Id> {
+    /// Reported when two vertices are declared with the same key.
+    DuplicateVertex(Key),
+
+    /// Reported when an edge refers to a key no vertex was declared with.
+    UnknownVertex(Key),
+
+    /// Reported when adding a vertex or edge to the graph fails.
+    Mutation(GraphMutationFailure<Id>),
+}
+
+impl<Key: Debug, Id: Display> Display for GraphBuildFailure<Key, Id> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GraphBuildFailure::DuplicateVertex(key) => {
+                write!(f, "Vertex key {key:?} was declared more than once.")
+            }
+            GraphBuildFailure::UnknownVertex(key) => {
+                write!(f, "Vertex key {key:?} was never declared.")
+            }
+            GraphBuildFailure::Mutation(failure) => {
+                write!(f, "Failed to build graph. {failure}")
+            }
+        }
+    }
+}
+
+impl<Key: Debug, Id: Debug + Display> Error for GraphBuildFailure<Key, Id> {}
+
+impl<Key, Id> From<GraphMutationFailure<Id>> for GraphBuildFailure<Key, Id> {
+    fn from(failure: GraphMutationFailure<Id>) -> Self {
+        GraphBuildFailure::Mutation(failure)
+    }
+}
+
+/// Graph produced by a `GraphBuilder`, with the identifier assigned to each
+/// vertex key.
+pub struct BuiltGraph<
+    Key: Clone + Eq + Hash,
+    Id: Copy + Eq + Hash + Display,
+    Data: Clone + PartialEq,
+    WeightData: Clone + PartialEq,
+    Registry: IdentifierRegistry<Id>,
+    S: BuildHasher + Clone + Default = RandomState,
+> {
+    pub graph: Graph<Id, Data, WeightData, Registry, S>,
+    pub ids: HashMap<Key, Id>,
+}
+
+/// Outcome of `GraphBuilder::build`.
+pub type GraphBuildResult<Key, Id, Data, WeightData, Registry, S> =
+    Result<BuiltGraph<Key, Id, Data, WeightData, Registry, S>, GraphBuildFailure<Key, Id>>;
+
+/// Graph Builder.
+///
+/// Collects vertex and edge declarations without touching a graph; `build`
+/// then creates the graph and reports the first problem found instead of
+/// panicking part way through construction.
+pub struct GraphBuilder<
+    Key: Clone + Eq + Hash,
+    Id: Copy + Eq + Hash + Display,
+    Data: Clone + PartialEq,
+    WeightData: Clone + PartialEq,
+    Registry: IdentifierRegistry<Id>,
+    S: BuildHasher + Clone + Default = RandomState,
+> {
+    graph: Graph<Id, Data, WeightData, Registry, S>,
+    vertices: Vec<(Key, Data)>,
+    edges: Vec<(Key, WeightData, Key)>,
+}
+
+impl<
+        Key: Clone + Eq + Hash,
+        Id: Copy + Eq + Hash + Display,
+        Data: Clone + PartialEq,
+        WeightData: Clone + PartialEq,
+        Registry: IdentifierRegistry<Id>,
+        S: BuildHasher + Clone + Default,
+    > GraphBuilder<Key, Id, Data, WeightData, Registry, S>
+{
+    /// Creates a builder for a graph with the given registries.
+    pub fn new(vertex_registry: Registry, edge_registry: Registry) -> Self {
+        Self::from_graph(Graph::new(vertex_registry, edge_registry))
+    }
+
+    /// Creates a builder that adds to the given (possibly configured) graph.
+    pub fn from_graph(graph: Graph<Id, Data, WeightData, Registry, S>) -> Self {
+        GraphBuilder {
+            graph,
+            vertices: Vec::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    /// Declares a vertex with the given key and data.
+    pub fn vertex(mut self, key: Key, data: Data) -> Self {
+        self.vertices.push((key, data));
+        self
+    }
+
+    /// Declares an edge between the vertices declared with the given keys.
+    pub fn edge(mut self, key_from: Key, data: WeightData, key_to: Key) -> Self {
+        self.edges.push((key_from, data, key_to));
+        self
+    }
+
+    /// Builds the graph, returning it along with the identifier assigned to
+    /// each vertex key, or the first problem found.
+    pub fn build(self) -> GraphBuildResult<Key, Id, Data, WeightData, Registry, S> {
+        let mut graph = self.graph;
+        let mut ids = HashMap::new();
+
+        for (key, data) in self.vertices {
+            if ids.contains_key(&key) {
+                return Err(GraphBuildFailure::DuplicateVertex(key));
+            }
+
+            let mut mutator = GraphVertexAdditionMutator::new(data);
+            graph = mutator.try_mutate(graph).map_err(|(_, failure)| failure)?;
+            ids.insert(key, mutator.vertex_id().unwrap());
+        }
+
+        for (key_from, data, key_to) in self.edges {
+            let lookup = |key: Key| {
+                ids.get(&key)
+                    .copied()
+                    .ok_or(GraphBuildFailure::UnknownVertex(key))
+            };
+            let (vertex_from, vertex_to) = (lookup(key_from)?, lookup(key_to)?);
+
+            graph = GraphEdgeAdditionMutator::new(vertex_from, data, vertex_to)
+                .try_mutate(graph)
+                .map_err(|(_, failure)| failure)?;
+        }
+
+        Ok(BuiltGraph { graph, ids })
+    }
+}
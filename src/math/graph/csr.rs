@@ -0,0 +1,271 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+//! Compressed Sparse Row Graph module.
+//!
+//! Provides an immutable graph representation that stores adjacency in
+//! contiguous arrays, for fast traversal of large, static graphs such as
+//! roadmaps.
+
+use crate::math::graph::*;
+
+/// Compressed Sparse Row (CSR) graph.
+///
+/// Stores vertices and edges in contiguous arrays, sorted by identifier, and
+/// the out (resp. in) adjacency of every vertex as a contiguous slice of a
+/// single array delimited by offsets. Lookups by identifier go through an
+/// index map; the graph's own traversals (e.g.
+/// `CsrGraph::breadth_first_traversal`) translate the source identifier once
+/// and then work on dense indices, touching only contiguous memory. The graph
+/// cannot be mutated; it is built from an existing `Graph` once construction
+/// is complete.
+#[derive(Clone)]
+pub struct CsrGraph<
+    Id: Copy + Eq + Hash + Display,
+    Data: Clone + PartialEq,
+    WeightData: Clone + PartialEq,
+> {
+    vertices: Vec<VertexDescriptor<Id, Data>>,
+    edges: Vec<EdgeDescriptor<Id, WeightData>>,
+    vertex_index: HashMap<Id, usize>,
+    edge_index: HashMap<Id, usize>,
+    out_offsets: Vec<usize>,
+    out_adjacency: Vec<(Id, Id)>,
+    out_indices: Vec<(usize, usize)>,
+    in_offsets: Vec<usize>,
+    in_adjacency: Vec<(Id, Id)>,
+}
+
+impl<Id: Copy + Eq + Hash + Display, Data: Clone + PartialEq, WeightData: Clone + PartialEq>
+    CsrGraph<Id, Data, WeightData>
+{
+    /// Builds a CSR graph with the same vertices, edges and adjacency (in the
+    /// same order) as the given graph. Vertices and edges are stored in order
+    /// of their identifiers, so the layout does not depend on the hasher of
+    /// the source graph. Reports adjacency that refers to an edge or vertex
+    /// missing from the graph as a `GraphViolation`.
+    pub fn from_graph<Registry: IdentifierRegistry<Id>, S: BuildHasher + Clone + Default>(
+        graph: &Graph<Id, Data, WeightData, Registry, S>,
+    ) -> Result<Self, GraphViolation<Id>>
+    where
+        Id: Ord,
+    {
+        let mut vertices: Vec<VertexDescriptor<Id, Data>> = graph.vertices().cloned().collect();
+        let mut edges: Vec<EdgeDescriptor<Id, WeightData>> = graph.edges().cloned().collect();
+        vertices.sort_by_key(|vertex| *vertex.id());
+        edges.sort_by_key(|edge| *edge.id());
+
+        let vertex_index: HashMap<Id, usize> = vertices
+            .iter()
+            .enumerate()
+            .map(|(i, vertex)| (*vertex.id(), i))
+            .collect();
+        let edge_index: HashMap<Id, usize> = edges
+            .iter()
+            .enumerate()
+            .map(|(i, edge)| (*edge.id(), i))
+            .collect();
+
+        let (out_offsets, out_adjacency) =
+            Self::compress(&vertices, |vertex_id| graph.out_adjacency(vertex_id));
+        let (in_offsets, in_adjacency) =
+            Self::compress(&vertices, |vertex_id| graph.in_adjacency(vertex_id));
+
+        let out_indices = out_adjacency
+            .iter()
+            .map(|(eid, vid)| {
+                let edge = edge_index
+                    .get(eid)
+                    .ok_or(GraphViolation::UnknownEdge(*eid))?;
+                let vertex = vertex_index
+                    .get(vid)
+                    .ok_or(GraphViolation::UnknownVertex(*vid))?;
+                Ok((*edge, *vertex))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(CsrGraph {
+            vertices,
+            edges,
+            vertex_index,
+            edge_index,
+            out_offsets,
+            out_adjacency,
+            out_indices,
+            in_offsets,
+            in_adjacency,
+        })
+    }
+
+    /// Returns the number of vertices in the graph.
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Returns the number of edges in the graph.
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Iterates over the vertices of the graph, in storage order.
+    pub fn vertices(&self) -> impl Iterator<Item = &VertexDescriptor<Id, Data>> {
+        self.vertices.iter()
+    }
+
+    /// Iterates over the edges of the graph, in storage order.
+    pub fn edges(&self) -> impl Iterator<Item = &EdgeDescriptor<Id, WeightData>> {
+        self.edges.iter()
+    }
+
+    /// Performs a breadth-first traversal from the given vertex, visiting
+    /// vertices and edges in the same order as `graph::breadth_first_traversal`
+    /// would on this graph, but without looking identifiers up along the way.
+    pub fn breadth_first_traversal<'a, V: GraphVisitor<'a, Id, Data, WeightData>>(
+        &'a self,
+        source: Id,
+        visitor: &mut V,
+    ) -> Result<(), GraphTraversalFailure<Id>> {
+        self.depth_limited_traversal(source, usize::MAX, visitor)
+            .map(|_| ())
+    }
+
+    /// Performs a depth-limited traversal from the given vertex, as
+    /// `graph::depth_limited_traversal` would on this graph, but without
+    /// looking identifiers up along the way.
+    pub fn depth_limited_traversal<'a, V: GraphVisitor<'a, Id, Data, WeightData>>(
+        &'a self,
+        source: Id,
+        max_depth: usize,
+        visitor: &mut V,
+    ) -> Result<bool, GraphTraversalFailure<Id>> {
+        let source_index = *self
+            .vertex_index
+            .get(&source)
+            .ok_or(GraphTraversalFailure::SourceNotFound(source))?;
+
+        let mut transition_queue = VecDeque::new();
+        let mut covered_vertices = vec![false; self.vertices.len()];
+        let mut truncated = false;
+
+        visitor.reset();
+
+        transition_queue.push_back((None::<(usize, usize)>, source_index, 0));
+        covered_vertices[source_index] = true;
+
+        while let Some((maybe_edge, vertex_index, depth)) = transition_queue.pop_front() {
+            let vertex = &self.vertices[vertex_index];
+
+            if let Some((from_vertex_index, edge_index)) = maybe_edge {
+                let from_vertex_id = *self.vertices[from_vertex_index].id();
+                visitor.visit_edge(from_vertex_id, &self.edges[edge_index], *vertex.id());
+            }
+
+            visitor.visit_vertex(vertex);
+
+            if !visitor.should_expand(vertex) {
+                continue;
+            }
+
+            let adjacency = &self.out_indices
+                [self.out_offsets[vertex_index]..self.out_offsets[vertex_index + 1]];
+            for &(edge_index, to_vertex_index) in adjacency {
+                if covered_vertices[to_vertex_index] {
+                    continue;
+                }
+
+                if depth == max_depth {
+                    truncated = true;
+                    continue;
+                }
+
+                covered_vertices[to_vertex_index] = true;
+                transition_queue.push_back((
+                    Some((vertex_index, edge_index)),
+                    to_vertex_index,
+                    depth + 1,
+                ));
+            }
+        }
+
+        Ok(truncated)
+    }
+
+    /// Concatenates the adjacency of every vertex, in vertex order, returning
+    /// the offsets delimiting each vertex's slice and the concatenation.
+    fn compress<'g, F: Fn(Id) -> &'g [(Id, Id)]>(
+        vertices: &[VertexDescriptor<Id, Data>],
+        adjacency_of: F,
+    ) -> (Vec<usize>, Vec<(Id, Id)>)
+    where
+        Id: 'g,
+    {
+        let mut offsets = Vec::with_capacity(vertices.len() + 1);
+        let mut adjacency = Vec::new();
+
+        offsets.push(0);
+        for vertex in vertices {
+            adjacency.extend_from_slice(adjacency_of(*vertex.id()));
+            offsets.push(adjacency.len());
+        }
+
+        (offsets, adjacency)
+    }
+}
+
+impl<Id: Copy + Eq + Hash + Display, Data: Clone + PartialEq, WeightData: Clone + PartialEq>
+    GraphStorage<Id, Data, WeightData> for CsrGraph<Id, Data, WeightData>
+{
+    fn vertex(&self, vertex_id: Id) -> Option<&VertexDescriptor<Id, Data>> {
+        self.vertex_index
+            .get(&vertex_id)
+            .map(|&index| &self.vertices[index])
+    }
+
+    fn edge(&self, edge_id: Id) -> Option<&EdgeDescriptor<Id, WeightData>> {
+        self.edge_index
+            .get(&edge_id)
+            .map(|&index| &self.edges[index])
+    }
+
+    fn out_adjacency(&self, vertex_id: Id) -> &[(Id, Id)] {
+        match self.vertex_index.get(&vertex_id) {
+            Some(&index) => {
+                &self.out_adjacency[self.out_offsets[index]..self.out_offsets[index + 1]]
+            }
+            None => &[],
+        }
+    }
+
+    fn in_adjacency(&self, vertex_id: Id) -> &[(Id, Id)] {
+        match self.vertex_index.get(&vertex_id) {
+            Some(&index) => &self.in_adjacency[self.in_offsets[index]..self.in_offsets[index + 1]],
+            None => &[],
+        }
+    }
+}
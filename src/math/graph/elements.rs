@@ -36,6 +36,7 @@ use std::fmt::Display;
 /// Pairs the (unique) vertex identifier with a (non-unique) vertex datum, fully
 /// describing a vertex in a graph.
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VertexDescriptor<Id: Copy + Eq + Hash + Display, Data: Clone + PartialEq> {
     id: Id,
     data: Data,
@@ -44,6 +45,7 @@ pub struct VertexDescriptor<Id: Copy + Eq + Hash + Display, Data: Clone + Partia
 /// Pairs the (unique) edge identifier with a (non-unique) edge datum, fully
 /// describing an edge in a graph.
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EdgeDescriptor<Id: Copy + Eq + Hash + Display, WeightData: Clone + PartialEq> {
     id: Id,
     data: WeightData,
@@ -64,6 +66,16 @@ pub trait GraphElement<IdType: Copy + PartialEq + Hash + Display, Data: Clone +
     fn with_data(&self, new_data: Data) -> Self;
 }
 
+/// Mutable Graph Element trait.
+///
+/// Graph element whose data can be modified in place.
+pub trait GraphElementMut<IdType: Copy + PartialEq + Hash + Display, Data: Clone + PartialEq>:
+    GraphElement<IdType, Data>
+{
+    /// Mutable access to the data associated with graph element.
+    fn data_mut(&mut self) -> &mut Data;
+}
+
 impl<Id: Copy + Eq + Hash + Display, Data: Clone + PartialEq> Hash for VertexDescriptor<Id, Data> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.id().hash(state)
@@ -89,7 +101,7 @@ impl<Id: Copy + Eq + Hash + Display, Data: Clone + PartialEq> GraphElement<Id, D
 
     fn with_data(&self, new_data: Data) -> Self {
         VertexDescriptor {
-            id: self.id().clone(),
+            id: *self.id(),
             data: new_data,
         }
     }
@@ -108,22 +120,38 @@ impl<Id: Copy + Eq + Hash + Display, Data: Clone + PartialEq> GraphElement<Id, D
 
     fn with_data(&self, new_data: Data) -> Self {
         EdgeDescriptor {
-            id: self.id().clone(),
+            id: *self.id(),
             data: new_data,
         }
     }
 }
 
+impl<Id: Copy + Eq + Hash + Display, Data: Clone + PartialEq> GraphElementMut<Id, Data>
+    for VertexDescriptor<Id, Data>
+{
+    fn data_mut(&mut self) -> &mut Data {
+        &mut self.data
+    }
+}
+
+impl<Id: Copy + Eq + Hash + Display, Data: Clone + PartialEq> GraphElementMut<Id, Data>
+    for EdgeDescriptor<Id, Data>
+{
+    fn data_mut(&mut self) -> &mut Data {
+        &mut self.data
+    }
+}
+
 pub fn make_edge<Id: Copy + Eq + Hash + Display, Data: Clone + PartialEq>(
     id: Id,
     data: Data,
 ) -> EdgeDescriptor<Id, Data> {
-    EdgeDescriptor { id: id, data: data }
+    EdgeDescriptor { id, data }
 }
 
 pub fn make_vertex<Id: Copy + Eq + Hash + Display, Data: Clone + PartialEq>(
     id: Id,
     data: Data,
 ) -> VertexDescriptor<Id, Data> {
-    VertexDescriptor { id: id, data: data }
+    VertexDescriptor { id, data }
 }
@@ -0,0 +1,93 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+//! Graph Export module.
+//!
+//! Renders graphs into text formats understood by external tools, chiefly for
+//! visual debugging.
+
+use crate::math::graph::*;
+use std::fmt::Write;
+
+/// Renders the graph in the Graphviz DOT language as a digraph.
+///
+/// Vertices are named by their identifiers and labelled with the result of
+/// `vertex_label`; edges are labelled with the result of `edge_label`. Names
+/// and labels are quoted and escaped, so they may contain arbitrary text. Reports an
+/// edge whose endpoints are missing as a `GraphViolation`.
+pub fn to_dot<
+    Id: Copy + Eq + Hash + Display,
+    Data: Clone + PartialEq,
+    WeightData: Clone + PartialEq,
+    Registry: IdentifierRegistry<Id>,
+    S: BuildHasher + Clone + Default,
+    VertexLabel: FnMut(&Data) -> String,
+    EdgeLabel: FnMut(&WeightData) -> String,
+>(
+    graph: &Graph<Id, Data, WeightData, Registry, S>,
+    mut vertex_label: VertexLabel,
+    mut edge_label: EdgeLabel,
+) -> Result<String, GraphViolation<Id>> {
+    let mut dot = String::from("digraph {\n");
+
+    for vertex in graph.vertices() {
+        let name = escape(&vertex.id().to_string());
+        let label = escape(&vertex_label(vertex.data()));
+        writeln!(dot, "    \"{name}\" [label=\"{label}\"];")
+            .expect("Writing to a string cannot fail.");
+    }
+
+    for edge in graph.edges() {
+        let (vertex_from, vertex_to) = graph
+            .edge_endpoints(*edge.id())
+            .ok_or(GraphViolation::UnmirroredEdge(*edge.id()))?;
+        let (name_from, name_to) = (
+            escape(&vertex_from.to_string()),
+            escape(&vertex_to.to_string()),
+        );
+        let label = escape(&edge_label(edge.data()));
+        writeln!(
+            dot,
+            "    \"{name_from}\" -> \"{name_to}\" [label=\"{label}\"];"
+        )
+        .expect("Writing to a string cannot fail.");
+    }
+
+    dot.push('}');
+    dot.push('\n');
+    Ok(dot)
+}
+
+/// Escapes a label for use inside a double-quoted DOT string.
+fn escape(label: &str) -> String {
+    label
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
@@ -32,11 +32,15 @@ SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 //! Provides traits and implementations supporting basic Graph representation
 //! and operations, such as graph mutation and path finding.
 
-use crate::utility::idregistry::IdentifierRegistry;
-use std::cmp::PartialEq;
-use std::collections::{HashMap, HashSet, LinkedList, VecDeque};
-use std::fmt::Display;
-use std::hash::Hash;
+use crate::utility::idregistry::{
+    IdentifierRegistry, IdentifierRegistryFailure, QueryableIdentifierRegistry,
+};
+use std::cmp::{Ordering, PartialEq, PartialOrd};
+use std::collections::hash_map::{DefaultHasher, RandomState};
+use std::collections::{BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{BuildHasher, BuildHasherDefault, Hash};
 
 pub mod elements;
 
@@ -49,20 +53,136 @@ use elements::*;
 /// registry mapping the vertices and edges to their identifiers; this allows
 /// users to store the data associated with their vertices and edges in the
 /// graph while primarily working with the (hopefully lightweight) identifiers.
+///
+/// The maps backing the graph are parameterized over their hasher `S`. The
+/// default `RandomState` seeds each map differently, so iteration order over
+/// vertices and edges varies between runs; use `DeterministicHashState` (or a
+/// faster hasher of your choosing) when that matters.
+///
+/// With the `serde` feature enabled, graphs serialize with their adjacency and
+/// registry state, so a deserialized graph continues to hand out fresh ids.
+/// Deserialized graphs are checked with `validate`, and ill-formed ones are
+/// rejected.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        try_from = "GraphRepr<Id, Data, WeightData, Registry, S>",
+        bound(
+            serialize = "Id: serde::Serialize, Data: serde::Serialize, \
+                         WeightData: serde::Serialize, Registry: serde::Serialize",
+            deserialize = "Id: serde::Deserialize<'de>, Data: serde::Deserialize<'de>, \
+                           WeightData: serde::Deserialize<'de>, \
+                           Registry: serde::Deserialize<'de> + QueryableIdentifierRegistry<Id>"
+        )
+    )
+)]
 pub struct Graph<
     Id: Copy + Eq + Hash + Display,
     Data: Clone + PartialEq,
     WeightData: Clone + PartialEq,
     Registry: IdentifierRegistry<Id>,
+    S: BuildHasher + Clone + Default = RandomState,
 > {
     vertex_id_registry: Registry,
     edge_id_registry: Registry,
-    vertices: HashMap<Id, VertexDescriptor<Id, Data>>,
-    edges: HashMap<Id, EdgeDescriptor<Id, WeightData>>,
-    forward_edges: HashMap<Id, Vec<(Id, Id)>>,
-    backward_edges: HashMap<Id, Vec<(Id, Id)>>,
+    vertices: HashMap<Id, VertexDescriptor<Id, Data>, S>,
+    edges: HashMap<Id, EdgeDescriptor<Id, WeightData>, S>,
+    forward_edges: HashMap<Id, Vec<(Id, Id)>, S>,
+    backward_edges: HashMap<Id, Vec<(Id, Id)>, S>,
+    edge_endpoints: HashMap<Id, (Id, Id), S>,
+    parallel_edge_policy: ParallelEdgePolicy,
+}
+
+/// Serialized form of `Graph`, checked before it becomes one.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(bound(
+    deserialize = "Id: serde::Deserialize<'de>, Data: serde::Deserialize<'de>, \
+                   WeightData: serde::Deserialize<'de>, Registry: serde::Deserialize<'de>"
+))]
+struct GraphRepr<
+    Id: Copy + Eq + Hash + Display,
+    Data: Clone + PartialEq,
+    WeightData: Clone + PartialEq,
+    Registry: IdentifierRegistry<Id>,
+    S: BuildHasher + Clone + Default,
+> {
+    vertex_id_registry: Registry,
+    edge_id_registry: Registry,
+    vertices: HashMap<Id, VertexDescriptor<Id, Data>, S>,
+    edges: HashMap<Id, EdgeDescriptor<Id, WeightData>, S>,
+    forward_edges: HashMap<Id, Vec<(Id, Id)>, S>,
+    backward_edges: HashMap<Id, Vec<(Id, Id)>, S>,
+    edge_endpoints: HashMap<Id, (Id, Id), S>,
+    parallel_edge_policy: ParallelEdgePolicy,
+}
+
+#[cfg(feature = "serde")]
+impl<
+        Id: Copy + Eq + Hash + Display,
+        Data: Clone + PartialEq,
+        WeightData: Clone + PartialEq,
+        Registry: QueryableIdentifierRegistry<Id>,
+        S: BuildHasher + Clone + Default,
+    > TryFrom<GraphRepr<Id, Data, WeightData, Registry, S>>
+    for Graph<Id, Data, WeightData, Registry, S>
+{
+    type Error = String;
+
+    fn try_from(repr: GraphRepr<Id, Data, WeightData, Registry, S>) -> Result<Self, Self::Error> {
+        let graph = Graph {
+            vertex_id_registry: repr.vertex_id_registry,
+            edge_id_registry: repr.edge_id_registry,
+            vertices: repr.vertices,
+            edges: repr.edges,
+            forward_edges: repr.forward_edges,
+            backward_edges: repr.backward_edges,
+            edge_endpoints: repr.edge_endpoints,
+            parallel_edge_policy: repr.parallel_edge_policy,
+        };
+
+        graph.validate().map_err(|violations| {
+            let violations: Vec<String> = violations.iter().map(ToString::to_string).collect();
+            format!("Graph is ill-formed. {}", violations.join(" "))
+        })?;
+        Ok(graph)
+    }
 }
 
+/// Parallel Edge Policy.
+///
+/// Decides what adding an edge does when the graph already has an edge from
+/// the same vertex to the same vertex.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ParallelEdgePolicy {
+    /// Keep both edges; the graph is a multigraph.
+    #[default]
+    Allow,
+
+    /// Fail the addition with `GraphMutationFailure::ParallelEdge`.
+    Reject,
+
+    /// Overwrite the data of the existing edge, keeping its identifier.
+    Replace,
+}
+
+/// Hasher state yielding a reproducible iteration order across graphs built by
+/// the same sequence of mutations.
+pub type DeterministicHashState = BuildHasherDefault<DefaultHasher>;
+
+/// Outcome of `GraphMutator::try_mutate`: the mutated graph, or the unchanged
+/// input graph along with the reason the mutation failed.
+pub type GraphMutationResult<Id, Data, WeightData, Registry, S = RandomState> = Result<
+    Graph<Id, Data, WeightData, Registry, S>,
+    (
+        Graph<Id, Data, WeightData, Registry, S>,
+        GraphMutationFailure<Id>,
+    ),
+>;
+
 /// Graph Mutator trait.
 ///
 /// A graph mutator moves the input graph and mutates it according to some rule
@@ -73,12 +193,224 @@ pub trait GraphMutator<
     Data: Clone + PartialEq,
     WeightData: Clone + PartialEq,
     Registry: IdentifierRegistry<Id>,
+    S: BuildHasher + Clone + Default = RandomState,
 >
 {
     fn mutate(
         &mut self,
-        graph: Graph<Id, Data, WeightData, Registry>,
-    ) -> Graph<Id, Data, WeightData, Registry>;
+        graph: Graph<Id, Data, WeightData, Registry, S>,
+    ) -> Graph<Id, Data, WeightData, Registry, S>;
+
+    /// Mutates the graph as `mutate` does, but reports failures instead of
+    /// panicking. On failure the input graph is handed back unchanged.
+    ///
+    /// By default, mutators are assumed to never fail.
+    // The graph is as large in the Ok variant, so boxing the Err one saves
+    // nothing.
+    #[allow(clippy::result_large_err)]
+    fn try_mutate(
+        &mut self,
+        graph: Graph<Id, Data, WeightData, Registry, S>,
+    ) -> GraphMutationResult<Id, Data, WeightData, Registry, S> {
+        Ok(self.mutate(graph))
+    }
+
+    /// Reverts the most recent successful application of this mutator, given
+    /// the graph it produced (or one since restored to it). A mutator applied
+    /// several times is undone in the reverse order of its applications. Used
+    /// by `mutators::GraphTransaction` to roll back without copying the graph.
+    fn undo(
+        &mut self,
+        graph: Graph<Id, Data, WeightData, Registry, S>,
+    ) -> Graph<Id, Data, WeightData, Registry, S>;
+
+    /// Chains this mutator with another that is applied to its output.
+    fn then<M: GraphMutator<Id, Data, WeightData, Registry, S>>(
+        self,
+        next: M,
+    ) -> mutators::Then<Self, M>
+    where
+        Self: Sized,
+    {
+        mutators::Then::new(self, next)
+    }
+
+    /// Applies this mutator the given number of times in a row.
+    fn repeat(self, times: usize) -> mutators::Repeat<Self>
+    where
+        Self: Sized,
+    {
+        mutators::Repeat::new(self, times)
+    }
+}
+
+/// Graph Mutation Failures.
+#[derive(Debug, PartialEq)]
+pub enum GraphMutationFailure<Id> {
+    /// Reported when a single-use mutator is applied a second time.
+    MutatorAlreadyUsed,
+
+    /// Reported when a mutator refers to a vertex that is not in the graph.
+    VertexNotFound(Id),
+
+    /// Reported when a mutator refers to an edge that is not in the graph.
+    EdgeNotFound(Id),
+
+    /// Reported when a new identifier could not be acquired from a registry.
+    IdentifierUnavailable(IdentifierRegistryFailure),
+
+    /// Reported when an edge would duplicate one between the same (from, to)
+    /// vertices and the graph rejects parallel edges.
+    ParallelEdge(Id, Id),
+}
+
+impl<Id: Display> Display for GraphMutationFailure<Id> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GraphMutationFailure::MutatorAlreadyUsed => {
+                write!(f, "Graph mutator has already been used.")
+            }
+            GraphMutationFailure::VertexNotFound(id) => {
+                write!(f, "Vertex id {id} was not found in graph.")
+            }
+            GraphMutationFailure::EdgeNotFound(id) => {
+                write!(f, "Edge id {id} was not found in graph.")
+            }
+            GraphMutationFailure::IdentifierUnavailable(failure) => {
+                write!(f, "Unable to acquire new identifier. {failure}")
+            }
+            GraphMutationFailure::ParallelEdge(from, to) => {
+                write!(f, "Vertices {from} and {to} are already joined by an edge.")
+            }
+        }
+    }
+}
+
+impl<Id: Debug + Display> Error for GraphMutationFailure<Id> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GraphMutationFailure::IdentifierUnavailable(failure) => Some(failure),
+            _ => None,
+        }
+    }
+}
+
+impl<Id> From<IdentifierRegistryFailure> for GraphMutationFailure<Id> {
+    fn from(failure: IdentifierRegistryFailure) -> Self {
+        GraphMutationFailure::IdentifierUnavailable(failure)
+    }
+}
+
+/// Graph Traversal Failures.
+#[derive(Debug, PartialEq)]
+pub enum GraphTraversalFailure<Id> {
+    /// Reported when the traversal is asked to begin on a vertex that is not
+    /// in the graph.
+    SourceNotFound(Id),
+
+    /// Reported when adjacency refers to a vertex that is not in the graph.
+    VertexNotFound(Id),
+
+    /// Reported when adjacency refers to an edge that is not in the graph.
+    EdgeNotFound(Id),
+
+    /// Reported when a best-first traversal computes a priority for the vertex
+    /// that cannot be compared, not even with itself (e.g. NaN).
+    IncomparablePriority(Id),
+
+    /// Reported when a result assembled from a traversal refers to part of the
+    /// graph that is missing or inconsistent.
+    IllFormed(GraphViolation<Id>),
+}
+
+impl<Id: Display> Display for GraphTraversalFailure<Id> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GraphTraversalFailure::SourceNotFound(id) => {
+                write!(
+                    f,
+                    "Traversal must begin on a vertex in the graph; {id} is not."
+                )
+            }
+            GraphTraversalFailure::VertexNotFound(id) => {
+                write!(
+                    f,
+                    "Graph is ill-formed. Expected vertex id {id} was not found in graph."
+                )
+            }
+            GraphTraversalFailure::EdgeNotFound(id) => {
+                write!(
+                    f,
+                    "Graph is ill-formed. Expected edge id {id} was not found in graph."
+                )
+            }
+            GraphTraversalFailure::IncomparablePriority(id) => {
+                write!(f, "Priority of vertex id {id} cannot be compared.")
+            }
+            GraphTraversalFailure::IllFormed(violation) => {
+                write!(f, "Graph is ill-formed. {violation}")
+            }
+        }
+    }
+}
+
+impl<Id: Debug + Display> Error for GraphTraversalFailure<Id> {}
+
+/// Graph Violations.
+///
+/// Structural invariants of a graph that `Graph::validate` found broken.
+#[derive(Debug, PartialEq)]
+pub enum GraphViolation<Id> {
+    /// Reported when a vertex id is referenced by adjacency but not in the graph.
+    UnknownVertex(Id),
+
+    /// Reported when an edge id is referenced by adjacency but not in the graph.
+    UnknownEdge(Id),
+
+    /// Reported when the forward adjacency, backward adjacency and endpoints
+    /// of an edge do not agree with one another.
+    UnmirroredEdge(Id),
+
+    /// Reported when the vertex registry considers an in-use vertex id free.
+    VertexIdNotReserved(Id),
+
+    /// Reported when the edge registry considers an in-use edge id free.
+    EdgeIdNotReserved(Id),
+}
+
+impl<Id: Display> Display for GraphViolation<Id> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GraphViolation::UnknownVertex(id) => {
+                write!(f, "Vertex id {id} is referenced but not in graph.")
+            }
+            GraphViolation::UnknownEdge(id) => {
+                write!(f, "Edge id {id} is referenced but not in graph.")
+            }
+            GraphViolation::UnmirroredEdge(id) => {
+                write!(f, "Edge id {id} has inconsistent adjacency.")
+            }
+            GraphViolation::VertexIdNotReserved(id) => {
+                write!(f, "Vertex id {id} is in use but free in its registry.")
+            }
+            GraphViolation::EdgeIdNotReserved(id) => {
+                write!(f, "Edge id {id} is in use but free in its registry.")
+            }
+        }
+    }
+}
+
+impl<Id: Debug + Display> Error for GraphViolation<Id> {}
+
+/// Walk Identifiers.
+///
+/// Owned form of a `Walk`, holding only the identifiers of its vertices and
+/// edges so it can be stored while the graph continues to be mutated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WalkIds<Id> {
+    pub vertices: Vec<Id>,
+    pub edges: Vec<Id>,
 }
 
 /// Walk.
@@ -95,6 +427,83 @@ pub struct Walk<
     edges: LinkedList<&'a EdgeDescriptor<Id, WeightData>>,
 }
 
+/// Edge adjacent to some vertex in a graph, paired with the vertex on its other
+/// end.
+pub type Neighbour<'a, Id, Data, WeightData> = (
+    &'a EdgeDescriptor<Id, WeightData>,
+    &'a VertexDescriptor<Id, Data>,
+);
+
+/// List of (edge, vertex) pairs adjacent to some vertex in a graph.
+pub type NeighbourList<'a, Id, Data, WeightData> = LinkedList<Neighbour<'a, Id, Data, WeightData>>;
+
+/// Graph Storage trait.
+///
+/// Read-only access to the vertices, edges and adjacency of a digraph. Graph
+/// algorithms are written against this trait so that they run unchanged on any
+/// representation of a graph, including views such as `Reversed`. Adjacency is
+/// reported as (edge id, vertex id) pairs; for out adjacency the vertex is the
+/// one the edge enters, and for in adjacency the one it leaves.
+pub trait GraphStorage<Id, Data, WeightData>
+where
+    Id: Copy + Eq + Hash + Display,
+    Data: Clone + PartialEq,
+    WeightData: Clone + PartialEq,
+{
+    /// Returns the vertex with the given id, if it is in the graph.
+    fn vertex(&self, vertex_id: Id) -> Option<&VertexDescriptor<Id, Data>>;
+
+    /// Returns the edge with the given id, if it is in the graph.
+    fn edge(&self, edge_id: Id) -> Option<&EdgeDescriptor<Id, WeightData>>;
+
+    /// Returns the edges leaving the given vertex, with the vertices they enter.
+    fn out_adjacency(&self, vertex_id: Id) -> &[(Id, Id)];
+
+    /// Returns the edges entering the given vertex, with the vertices they leave.
+    fn in_adjacency(&self, vertex_id: Id) -> &[(Id, Id)];
+}
+
+/// Reversed Graph view.
+///
+/// Borrows a graph and presents it with every edge reversed, by swapping its
+/// out and in adjacency. Unlike `Graph::reverse_graph`, the original graph is
+/// left untouched and usable once the view is dropped.
+pub struct Reversed<'a, G> {
+    graph: &'a G,
+}
+
+impl<'a, G> Reversed<'a, G> {
+    /// Creates a reversed view of the given graph.
+    pub fn new(graph: &'a G) -> Self {
+        Reversed { graph }
+    }
+}
+
+impl<
+        'a,
+        Id: Copy + Eq + Hash + Display,
+        Data: Clone + PartialEq,
+        WeightData: Clone + PartialEq,
+        G: GraphStorage<Id, Data, WeightData>,
+    > GraphStorage<Id, Data, WeightData> for Reversed<'a, G>
+{
+    fn vertex(&self, vertex_id: Id) -> Option<&VertexDescriptor<Id, Data>> {
+        self.graph.vertex(vertex_id)
+    }
+
+    fn edge(&self, edge_id: Id) -> Option<&EdgeDescriptor<Id, WeightData>> {
+        self.graph.edge(edge_id)
+    }
+
+    fn out_adjacency(&self, vertex_id: Id) -> &[(Id, Id)] {
+        self.graph.in_adjacency(vertex_id)
+    }
+
+    fn in_adjacency(&self, vertex_id: Id) -> &[(Id, Id)] {
+        self.graph.out_adjacency(vertex_id)
+    }
+}
+
 /// Graph Visitor trait.
 ///
 /// Provides an adapter to graph algorithms that allow for custom logic when
@@ -113,39 +522,130 @@ where
         edge: &'a EdgeDescriptor<Id, WeightData>,
         vertex_to: Id,
     );
+
+    /// Decides, once a vertex has been visited, whether the traversal
+    /// continues past it. Returning false prunes the vertex's descendants
+    /// (unless reached another way) while the rest of the traversal proceeds.
+    fn should_expand(&mut self, _vertex: &'a VertexDescriptor<Id, Data>) -> bool {
+        true
+    }
+}
+
+/// Discovery metadata of a vertex reached by a breadth-first traversal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Discovery<Id> {
+    /// Number of edges between the source and the vertex.
+    pub depth: usize,
+
+    /// Vertex the traversal reached this vertex from, or `None` for the source.
+    pub predecessor: Option<Id>,
+
+    /// Position of the vertex in discovery order, starting at zero.
+    pub index: usize,
+}
+
+/// Traversal Visitor trait.
+///
+/// A richer `GraphVisitor` whose vertex callback also receives the vertex's
+/// `Discovery` metadata, for algorithms that need depths or predecessors
+/// without reimplementing the traversal.
+pub trait TraversalVisitor<'a, Id, Data, WeightData>
+where
+    Id: Copy + Eq + Hash + Display,
+    Data: Clone + PartialEq,
+    WeightData: Clone + PartialEq,
+{
+    fn reset(&mut self);
+    fn visit_vertex(&mut self, vertex: &'a VertexDescriptor<Id, Data>, discovery: Discovery<Id>);
+    fn visit_edge(
+        &mut self,
+        vertex_from: Id,
+        edge: &'a EdgeDescriptor<Id, WeightData>,
+        vertex_to: Id,
+    );
+
+    /// Decides, as `GraphVisitor::should_expand` does, whether the traversal
+    /// continues past a visited vertex.
+    fn should_expand(&mut self, _vertex: &'a VertexDescriptor<Id, Data>) -> bool {
+        true
+    }
+}
+
+/// Adapts a `GraphVisitor` to a `TraversalVisitor` by discarding discovery
+/// metadata.
+struct IgnoreDiscovery<'v, V>(&'v mut V);
+
+impl<
+        'a,
+        'v,
+        Id: Copy + Eq + Hash + Display,
+        Data: Clone + PartialEq,
+        WeightData: Clone + PartialEq,
+        V: GraphVisitor<'a, Id, Data, WeightData>,
+    > TraversalVisitor<'a, Id, Data, WeightData> for IgnoreDiscovery<'v, V>
+{
+    fn reset(&mut self) {
+        self.0.reset()
+    }
+
+    fn visit_vertex(&mut self, vertex: &'a VertexDescriptor<Id, Data>, _: Discovery<Id>) {
+        self.0.visit_vertex(vertex)
+    }
+
+    fn visit_edge(
+        &mut self,
+        vertex_from: Id,
+        edge: &'a EdgeDescriptor<Id, WeightData>,
+        vertex_to: Id,
+    ) {
+        self.0.visit_edge(vertex_from, edge, vertex_to)
+    }
+
+    fn should_expand(&mut self, vertex: &'a VertexDescriptor<Id, Data>) -> bool {
+        self.0.should_expand(vertex)
+    }
 }
 
 impl<
         Id: Copy + Eq + Hash + Display,
         Registry: IdentifierRegistry<Id>,
+        S: BuildHasher + Clone + Default,
         Data: Clone + PartialEq,
         WeightData: Clone + PartialEq,
-    > Graph<Id, Data, WeightData, Registry>
+    > Graph<Id, Data, WeightData, Registry, S>
 {
     /// Creates a new (empty) graph with the given registries.
     pub fn new(
         vertex_registry: Registry,
         edge_registry: Registry,
-    ) -> Graph<Id, Data, WeightData, Registry> {
+    ) -> Graph<Id, Data, WeightData, Registry, S> {
         Graph {
             vertex_id_registry: vertex_registry,
             edge_id_registry: edge_registry,
-            vertices: HashMap::new(),
-            edges: HashMap::new(),
-            forward_edges: HashMap::new(),
-            backward_edges: HashMap::new(),
+            vertices: HashMap::default(),
+            edges: HashMap::default(),
+            forward_edges: HashMap::default(),
+            backward_edges: HashMap::default(),
+            edge_endpoints: HashMap::default(),
+            parallel_edge_policy: ParallelEdgePolicy::default(),
         }
     }
 
+    /// Sets what adding an edge parallel to an existing one does; by default
+    /// parallel edges are allowed.
+    pub fn with_parallel_edge_policy(mut self, policy: ParallelEdgePolicy) -> Self {
+        self.parallel_edge_policy = policy;
+        self
+    }
+
+    /// Returns the graph's parallel edge policy.
+    pub fn parallel_edge_policy(&self) -> ParallelEdgePolicy {
+        self.parallel_edge_policy
+    }
+
     /// Returns a list of edges and vertices that are (out) neighbours of the
     /// given vertex.
-    pub fn neighbours_of<'a>(
-        &'a self,
-        vertex_id: Id,
-    ) -> LinkedList<(
-        &'a EdgeDescriptor<Id, WeightData>,
-        &'a VertexDescriptor<Id, Data>,
-    )> {
+    pub fn neighbours_of(&self, vertex_id: Id) -> NeighbourList<'_, Id, Data, WeightData> {
         self.out_neighbours_of(vertex_id)
     }
 
@@ -153,83 +653,95 @@ impl<
     /// vertex is the out neighbour of the first vertex. Returns true if they
     /// are adjacent, false otherwise.
     pub fn is_adjacent(&self, vertex_from: Id, vertex_to: Id) -> bool {
-        self.out_neighbours_of(vertex_from)
-            .iter()
-            .find(|(_, vid_to)| *vid_to.id() == vertex_to)
-            .is_some()
+        self.out_neighbours(vertex_from)
+            .any(|(_, vid_to)| *vid_to.id() == vertex_to)
     }
 
-    pub fn out_neighbours_of<'a>(
-        &'a self,
+    /// Returns the first edge added from one vertex to another, if any.
+    pub fn get_edge_between(
+        &self,
+        vertex_from: Id,
+        vertex_to: Id,
+    ) -> Option<&EdgeDescriptor<Id, WeightData>> {
+        self.edges_between(vertex_from, vertex_to).next()
+    }
+
+    /// Iterates over all (parallel) edges from one vertex to another, in the
+    /// order they were added.
+    pub fn edges_between(
+        &self,
+        vertex_from: Id,
+        vertex_to: Id,
+    ) -> impl Iterator<Item = &EdgeDescriptor<Id, WeightData>> {
+        self.out_neighbours(vertex_from)
+            .filter(move |(_, vertex)| *vertex.id() == vertex_to)
+            .map(|(edge, _)| edge)
+    }
+
+    /// Returns a list of edges and vertices that are out neighbours of the
+    /// given vertex, collected from `out_neighbours`.
+    pub fn out_neighbours_of(&self, vertex_id: Id) -> NeighbourList<'_, Id, Data, WeightData> {
+        self.out_neighbours(vertex_id).collect()
+    }
+
+    /// Returns a list of edges and vertices that are in neighbours of the
+    /// given vertex, collected from `in_neighbours`.
+    pub fn in_neighbours_of(&self, vertex_id: Id) -> NeighbourList<'_, Id, Data, WeightData> {
+        self.in_neighbours(vertex_id).collect()
+    }
+
+    /// Lazily iterates over the edges leaving the given vertex, paired with
+    /// the vertex each of them enters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the graph is ill-formed, i.e. the vertex's adjacency refers to
+    /// an edge or vertex not in the graph; see `validate`. The same holds for
+    /// every query built on it, e.g. `out_neighbours_of` and `edges_between`.
+    pub fn out_neighbours(
+        &self,
         vertex_id: Id,
-    ) -> LinkedList<(
-        &'a EdgeDescriptor<Id, WeightData>,
-        &'a VertexDescriptor<Id, Data>,
-    )> {
-        self.forward_edges
-            .get(&vertex_id)
-            .cloned()
-            .unwrap_or(Vec::new())
-            .iter()
-            .map(|(eid, vid)| {
-                let edge = self.edges.get(eid);
-                let vertex = self.vertices.get(vid);
-
-                (
-                    edge.expect(
-                        format!(
-                            "Graph is ill-formed. Expected edge id {eid} was not found in graph."
-                        )
-                        .as_str(),
-                    ),
-                    vertex.expect(
-                        format!(
-                            "Graph is ill-formed. Expected vertex id {vid} was not found in graph."
-                        )
-                        .as_str(),
-                    ),
-                )
-            })
-            .collect()
+    ) -> impl Iterator<Item = Neighbour<'_, Id, Data, WeightData>> {
+        self.adjacent_descriptors(self.forward_edges.get(&vertex_id))
     }
 
-    pub fn in_neighbours_of<'a>(
-        &'a self,
+    /// Lazily iterates over the edges entering the given vertex, paired with
+    /// the vertex each of them leaves.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the graph is ill-formed, as `out_neighbours` does.
+    pub fn in_neighbours(
+        &self,
         vertex_id: Id,
-    ) -> LinkedList<(
-        &'a EdgeDescriptor<Id, WeightData>,
-        &'a VertexDescriptor<Id, Data>,
-    )> {
-        self.backward_edges
-            .get(&vertex_id)
-            .cloned()
-            .unwrap_or(Vec::new())
-            .iter()
-            .map(|(eid, vid)| {
-                let edge = self.edges.get(eid);
-                let vertex = self.vertices.get(vid);
-
-                (
-                    edge.expect(
-                        format!(
-                            "Graph is ill-formed. Expected edge id {eid} was not found in graph."
-                        )
-                        .as_str(),
-                    ),
-                    vertex.expect(
-                        format!(
-                            "Graph is ill-formed. Expected vertex id {vid} was not found in graph."
-                        )
-                        .as_str(),
-                    ),
-                )
-            })
-            .collect()
+    ) -> impl Iterator<Item = Neighbour<'_, Id, Data, WeightData>> {
+        self.adjacent_descriptors(self.backward_edges.get(&vertex_id))
+    }
+
+    /// Resolves the (edge, vertex) identifier pairs of an adjacency list into
+    /// their descriptors.
+    fn adjacent_descriptors<'a>(
+        &'a self,
+        adjacency: Option<&'a Vec<(Id, Id)>>,
+    ) -> impl Iterator<Item = Neighbour<'a, Id, Data, WeightData>> {
+        adjacency.into_iter().flatten().map(|(eid, vid)| {
+            let edge = self.edges.get(eid);
+            let vertex = self.vertices.get(vid);
+
+            (
+                edge.unwrap_or_else(|| {
+                    panic!("Graph is ill-formed. Expected edge id {eid} was not found in graph.")
+                }),
+                vertex.unwrap_or_else(|| {
+                    panic!("Graph is ill-formed. Expected vertex id {vid} was not found in graph.")
+                }),
+            )
+        })
     }
 
     /// Creates a graph with the same vertices and edges except the edges
     /// are reversed.
-    pub fn reverse_graph(self) -> Graph<Id, Data, WeightData, Registry> {
+    pub fn reverse_graph(self) -> Graph<Id, Data, WeightData, Registry, S> {
         Graph {
             vertex_id_registry: self.vertex_id_registry,
             edge_id_registry: self.edge_id_registry,
@@ -237,24 +749,397 @@ impl<
             edges: self.edges,
             forward_edges: self.backward_edges,
             backward_edges: self.forward_edges,
+            edge_endpoints: self
+                .edge_endpoints
+                .into_iter()
+                .map(|(edge_id, (vertex_from, vertex_to))| (edge_id, (vertex_to, vertex_from)))
+                .collect(),
+            parallel_edge_policy: self.parallel_edge_policy,
         }
     }
 
-    pub fn select_vertices_with_data<'a>(
-        &'a self,
-        desc: Data,
-    ) -> LinkedList<&'a VertexDescriptor<Id, Data>> {
+    /// Returns a view of the graph with every edge reversed, leaving the graph
+    /// itself untouched.
+    pub fn reversed(&self) -> Reversed<'_, Self> {
+        Reversed::new(self)
+    }
+
+    /// Returns the number of vertices in the graph.
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Returns the number of edges in the graph.
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Iterates over the vertices of the graph, in no particular order.
+    pub fn vertices(&self) -> impl Iterator<Item = &VertexDescriptor<Id, Data>> {
+        self.vertices.values()
+    }
+
+    /// Iterates over the edges of the graph, in no particular order.
+    pub fn edges(&self) -> impl Iterator<Item = &EdgeDescriptor<Id, WeightData>> {
+        self.edges.values()
+    }
+
+    /// Returns mutable access to the data of the given vertex, or `None` if
+    /// the vertex is not in the graph. Unlike `mutators::map_vertex`, this
+    /// updates the data in place.
+    pub fn get_vertex_mut(&mut self, vertex_id: Id) -> Option<&mut Data> {
+        self.vertices
+            .get_mut(&vertex_id)
+            .map(|vertex| vertex.data_mut())
+    }
+
+    /// Returns mutable access to the data of the given edge, or `None` if the
+    /// edge is not in the graph. Unlike `mutators::map_edge`, this updates the
+    /// data in place.
+    pub fn get_edge_mut(&mut self, edge_id: Id) -> Option<&mut WeightData> {
+        self.edges.get_mut(&edge_id).map(|edge| edge.data_mut())
+    }
+
+    /// Returns the identifiers of the vertices the given edge leaves and
+    /// enters, respectively, or `None` if the edge is not in the graph.
+    pub fn edge_endpoints(&self, edge_id: Id) -> Option<(Id, Id)> {
+        self.edge_endpoints.get(&edge_id).copied()
+    }
+
+    pub fn select_vertices_with_data(&self, desc: Data) -> LinkedList<&VertexDescriptor<Id, Data>> {
         self.vertices
             .values()
             .filter(|other_desc| desc == *other_desc.data())
             .collect()
     }
+
+    /// Returns the edges whose data satisfies the given predicate.
+    pub fn select_edges<P: FnMut(&WeightData) -> bool>(
+        &self,
+        mut predicate: P,
+    ) -> LinkedList<&EdgeDescriptor<Id, WeightData>> {
+        self.edges
+            .values()
+            .filter(|edge| predicate(edge.data()))
+            .collect()
+    }
+
+    /// Returns the edges leaving a vertex whose data satisfies `from` and
+    /// entering a vertex whose data satisfies `to`, or the first violation
+    /// found if the graph is ill-formed, i.e. an edge's endpoints are missing.
+    pub fn select_edges_between<PFrom: FnMut(&Data) -> bool, PTo: FnMut(&Data) -> bool>(
+        &self,
+        mut from: PFrom,
+        mut to: PTo,
+    ) -> Result<LinkedList<&EdgeDescriptor<Id, WeightData>>, GraphViolation<Id>> {
+        let vertex_data = |vid: Id| {
+            self.vertices
+                .get(&vid)
+                .map(|vertex| vertex.data())
+                .ok_or(GraphViolation::UnknownVertex(vid))
+        };
+
+        let mut selected = LinkedList::new();
+        for edge in self.edges.values() {
+            let eid = *edge.id();
+            let (vertex_from, vertex_to) = *self
+                .edge_endpoints
+                .get(&eid)
+                .ok_or(GraphViolation::UnmirroredEdge(eid))?;
+
+            if from(vertex_data(vertex_from)?) && to(vertex_data(vertex_to)?) {
+                selected.push_back(edge);
+            }
+        }
+
+        Ok(selected)
+    }
+}
+
+impl<
+        Id: Copy + Eq + Hash + Display,
+        Registry: QueryableIdentifierRegistry<Id>,
+        S: BuildHasher + Clone + Default,
+        Data: Clone + PartialEq,
+        WeightData: Clone + PartialEq,
+    > Graph<Id, Data, WeightData, Registry, S>
+{
+    /// Checks the structural invariants of the graph: the forward and backward
+    /// adjacency mirror each other and the recorded edge endpoints, every
+    /// referenced id is in the graph, and no id in use is free in its registry.
+    /// Returns every violation found, or nothing if the graph is well-formed.
+    pub fn validate(&self) -> Result<(), Vec<GraphViolation<Id>>> {
+        let mut violations = Vec::new();
+        let mut report = |violation: GraphViolation<Id>| {
+            if !violations.contains(&violation) {
+                violations.push(violation);
+            }
+        };
+
+        for (edge_id, (vertex_from, vertex_to)) in self.edge_endpoints.iter() {
+            if !self.edges.contains_key(edge_id) {
+                report(GraphViolation::UnknownEdge(*edge_id));
+            }
+            for vertex_id in [vertex_from, vertex_to] {
+                if !self.vertices.contains_key(vertex_id) {
+                    report(GraphViolation::UnknownVertex(*vertex_id));
+                }
+            }
+
+            let forward = self
+                .forward_edges
+                .get(vertex_from)
+                .is_some_and(|adjacency| adjacency.contains(&(*edge_id, *vertex_to)));
+            let backward = self
+                .backward_edges
+                .get(vertex_to)
+                .is_some_and(|adjacency| adjacency.contains(&(*edge_id, *vertex_from)));
+            if !forward || !backward {
+                report(GraphViolation::UnmirroredEdge(*edge_id));
+            }
+        }
+
+        for (adjacency, reversed) in [(&self.forward_edges, false), (&self.backward_edges, true)] {
+            for (vertex_id, neighbours) in adjacency.iter() {
+                if !self.vertices.contains_key(vertex_id) {
+                    report(GraphViolation::UnknownVertex(*vertex_id));
+                }
+                for (edge_id, other_id) in neighbours.iter() {
+                    if !self.edges.contains_key(edge_id) {
+                        report(GraphViolation::UnknownEdge(*edge_id));
+                    }
+                    let endpoints = if reversed {
+                        (*other_id, *vertex_id)
+                    } else {
+                        (*vertex_id, *other_id)
+                    };
+                    if self.edge_endpoints.get(edge_id) != Some(&endpoints) {
+                        report(GraphViolation::UnmirroredEdge(*edge_id));
+                    }
+                }
+            }
+        }
+
+        for edge_id in self.edges.keys() {
+            if !self.edge_endpoints.contains_key(edge_id) {
+                report(GraphViolation::UnmirroredEdge(*edge_id));
+            }
+            if self.edge_id_registry.is_free(edge_id) {
+                report(GraphViolation::EdgeIdNotReserved(*edge_id));
+            }
+        }
+
+        for vertex_id in self.vertices.keys() {
+            if self.vertex_id_registry.is_free(vertex_id) {
+                report(GraphViolation::VertexIdNotReserved(*vertex_id));
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+impl<
+        Id: Copy + Eq + Hash + Display,
+        Registry: IdentifierRegistry<Id>,
+        S: BuildHasher + Clone + Default,
+        Data: Clone + PartialEq,
+        WeightData: Clone + PartialEq,
+    > Display for Graph<Id, Data, WeightData, Registry, S>
+{
+    /// Summarizes the graph by its size, e.g. "Graph (3 vertices, 2 edges)".
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Graph ({} vertices, {} edges)",
+            self.vertex_count(),
+            self.edge_count()
+        )
+    }
+}
+
+impl<
+        Id: Copy + Eq + Hash + Display,
+        Registry: IdentifierRegistry<Id>,
+        S: BuildHasher + Clone + Default,
+        Data: Clone + PartialEq,
+        WeightData: Clone + PartialEq,
+    > GraphStorage<Id, Data, WeightData> for Graph<Id, Data, WeightData, Registry, S>
+{
+    fn vertex(&self, vertex_id: Id) -> Option<&VertexDescriptor<Id, Data>> {
+        self.vertices.get(&vertex_id)
+    }
+
+    fn edge(&self, edge_id: Id) -> Option<&EdgeDescriptor<Id, WeightData>> {
+        self.edges.get(&edge_id)
+    }
+
+    fn out_adjacency(&self, vertex_id: Id) -> &[(Id, Id)] {
+        self.forward_edges
+            .get(&vertex_id)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    fn in_adjacency(&self, vertex_id: Id) -> &[(Id, Id)] {
+        self.backward_edges
+            .get(&vertex_id)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
 }
 
+impl<
+        'a,
+        Id: Copy + Eq + Hash + Display,
+        Data: Clone + PartialEq,
+        WeightData: Clone + PartialEq,
+    > Walk<'a, Id, Data, WeightData>
+{
+    /// Returns the vertices of the walk, in order, starting at the first vertex.
+    pub fn vertices(&self) -> impl Iterator<Item = &'a VertexDescriptor<Id, Data>> + '_ {
+        self.vertices.iter().copied()
+    }
+
+    /// Returns the edges transited by the walk, in order.
+    pub fn edges(&self) -> impl Iterator<Item = &'a EdgeDescriptor<Id, WeightData>> + '_ {
+        self.edges.iter().copied()
+    }
+
+    /// Returns the identifier of the vertex the walk starts at.
+    pub fn start(&self) -> Id {
+        *self
+            .vertices
+            .front()
+            .expect("Walk is ill-formed. A walk must contain at least one vertex.")
+            .id()
+    }
+
+    /// Returns the identifier of the vertex the walk ends at.
+    pub fn end(&self) -> Id {
+        *self
+            .vertices
+            .back()
+            .expect("Walk is ill-formed. A walk must contain at least one vertex.")
+            .id()
+    }
+
+    /// Returns the number of edges transited by the walk.
+    pub fn hop_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Folds the weights of the transited edges, in order, into a total cost
+    /// starting from the given initial value.
+    pub fn total_cost<C, F: FnMut(C, &WeightData) -> C>(&self, initial: C, accumulate: F) -> C {
+        self.edges
+            .iter()
+            .map(|edge| edge.data())
+            .fold(initial, accumulate)
+    }
+
+    /// Joins this walk with another that starts where this one ends.
+    ///
+    /// Returns `None` if the end of this walk is not the start of the other.
+    pub fn concat(self, other: Self) -> Option<Self> {
+        if self.end() != other.start() {
+            return None;
+        }
+
+        let mut vertices = self.vertices;
+        let mut edges = self.edges;
+        let mut other_vertices = other.vertices;
+        let mut other_edges = other.edges;
+
+        other_vertices.pop_front();
+        vertices.append(&mut other_vertices);
+        edges.append(&mut other_edges);
+
+        Some(Walk { vertices, edges })
+    }
+
+    /// Checks that the walk exists in the given graph, i.e. that every edge of
+    /// the walk is in the graph and goes from the vertex preceding it in the
+    /// walk to the vertex following it.
+    pub fn is_valid_in<G: GraphStorage<Id, Data, WeightData>>(&self, graph: &G) -> bool {
+        if self.vertices.len() != self.edges.len() + 1 {
+            return false;
+        }
+
+        let mut vertices = self.vertices.iter();
+        let mut vertex_from = match vertices.next() {
+            Some(vertex) => *vertex.id(),
+            None => return false,
+        };
+
+        if graph.vertex(vertex_from).is_none() {
+            return false;
+        }
+
+        for (edge, vertex_to) in self.edges.iter().zip(vertices) {
+            let transition = (*edge.id(), *vertex_to.id());
+            let adjacent = graph.out_adjacency(vertex_from).contains(&transition);
+
+            if !adjacent {
+                return false;
+            }
+
+            vertex_from = *vertex_to.id();
+        }
+
+        true
+    }
+
+    /// Returns the identifiers of the walk's vertices and edges, which, unlike
+    /// the walk, do not borrow the graph.
+    pub fn to_ids(&self) -> WalkIds<Id> {
+        WalkIds {
+            vertices: self.vertices().map(|vertex| *vertex.id()).collect(),
+            edges: self.edges().map(|edge| *edge.id()).collect(),
+        }
+    }
+}
+
+impl<Id: Copy + Eq + Hash + Display> WalkIds<Id> {
+    /// Looks the walk's vertices and edges up in the given graph, or returns
+    /// `None` if the walk is no longer valid in it.
+    pub fn resolve<
+        'a,
+        Data: Clone + PartialEq,
+        WeightData: Clone + PartialEq,
+        G: GraphStorage<Id, Data, WeightData>,
+    >(
+        &self,
+        graph: &'a G,
+    ) -> Option<Walk<'a, Id, Data, WeightData>> {
+        let walk = Walk {
+            vertices: self
+                .vertices
+                .iter()
+                .map(|vertex_id| graph.vertex(*vertex_id))
+                .collect::<Option<_>>()?,
+            edges: self
+                .edges
+                .iter()
+                .map(|edge_id| graph.edge(*edge_id))
+                .collect::<Option<_>>()?,
+        };
+
+        if walk.is_valid_in(graph) {
+            Some(walk)
+        } else {
+            None
+        }
+    }
+}
 
 /// Vertex Collector.
-/// 
-/// Collects vertices into a linked list as they are visited, in-order, by 
+///
+/// Collects vertices into a linked list as they are visited, in-order, by
 /// reference.
 pub struct VertexCollector<
     'a,
@@ -272,7 +1157,7 @@ impl<'a, Id: Copy + Eq + Hash + Display, Data: Clone + PartialEq, F: Fn(&Data) -
     pub fn new(selector: F) -> Self {
         VertexCollector {
             vertices: LinkedList::new(),
-            selector: selector,
+            selector,
         }
     }
 
@@ -302,38 +1187,203 @@ impl<
     fn visit_edge(&mut self, _: Id, _: &'a EdgeDescriptor<Id, WeightData>, _: Id) {}
 }
 
+pub mod builder;
+pub mod csr;
+pub mod export;
 pub mod mutators;
+pub mod observer;
+#[cfg(feature = "parallel")]
+pub mod parallel;
+pub mod pathfinding;
+#[allow(clippy::module_inception)]
 mod tests;
 
+/// Graph Diff.
+///
+/// Identifiers of the vertices and edges that differ between two graphs, as
+/// computed by `diff`. Changed elements are those present in both graphs whose
+/// data (or, for edges, endpoints) differ.
+#[derive(Debug, PartialEq)]
+pub struct GraphDiff<Id> {
+    pub added_vertices: Vec<Id>,
+    pub removed_vertices: Vec<Id>,
+    pub changed_vertices: Vec<Id>,
+    pub added_edges: Vec<Id>,
+    pub removed_edges: Vec<Id>,
+    pub changed_edges: Vec<Id>,
+}
+
+impl<Id> GraphDiff<Id> {
+    /// Checks if the two graphs compared were identical.
+    pub fn is_empty(&self) -> bool {
+        self.added_vertices.is_empty()
+            && self.removed_vertices.is_empty()
+            && self.changed_vertices.is_empty()
+            && self.added_edges.is_empty()
+            && self.removed_edges.is_empty()
+            && self.changed_edges.is_empty()
+    }
+}
+
+/// Compares two graphs element by element, matching vertices and edges by
+/// identifier and their data by `PartialEq`. The result describes how to turn
+/// the old graph into the new one.
+pub fn diff<
+    Id: Copy + Eq + Hash + Display,
+    Data: Clone + PartialEq,
+    WeightData: Clone + PartialEq,
+    Registry: IdentifierRegistry<Id>,
+    S: BuildHasher + Clone + Default,
+>(
+    old: &Graph<Id, Data, WeightData, Registry, S>,
+    new: &Graph<Id, Data, WeightData, Registry, S>,
+) -> GraphDiff<Id> {
+    let mut result = GraphDiff {
+        added_vertices: Vec::new(),
+        removed_vertices: Vec::new(),
+        changed_vertices: Vec::new(),
+        added_edges: Vec::new(),
+        removed_edges: Vec::new(),
+        changed_edges: Vec::new(),
+    };
+
+    for (vertex_id, vertex) in new.vertices.iter() {
+        match old.vertices.get(vertex_id) {
+            None => result.added_vertices.push(*vertex_id),
+            Some(old_vertex) if old_vertex.data() != vertex.data() => {
+                result.changed_vertices.push(*vertex_id)
+            }
+            Some(_) => {}
+        }
+    }
+    result.removed_vertices = old
+        .vertices
+        .keys()
+        .filter(|vertex_id| !new.vertices.contains_key(vertex_id))
+        .copied()
+        .collect();
+
+    for (edge_id, edge) in new.edges.iter() {
+        match old.edges.get(edge_id) {
+            None => result.added_edges.push(*edge_id),
+            Some(old_edge)
+                if old_edge.data() != edge.data()
+                    || old.edge_endpoints(*edge_id) != new.edge_endpoints(*edge_id) =>
+            {
+                result.changed_edges.push(*edge_id)
+            }
+            Some(_) => {}
+        }
+    }
+    result.removed_edges = old
+        .edges
+        .keys()
+        .filter(|edge_id| !new.edges.contains_key(edge_id))
+        .copied()
+        .collect();
+
+    result
+}
+
 /// Breadth-First Traversal.
 ///
 /// Performs a breadth-first traversal (BFT) on the graph from the given vertex
 /// and applies the provided visitor to every edge and vertex it visits in
 /// order. Due to how BFT is performed, the traversal of an edge happens just
 /// before the out vertex it corresponds to is visited.
+///
+/// Fails if the source vertex is not in the graph, or if the graph is
+/// ill-formed, i.e. its adjacency refers to missing vertices or edges.
 pub fn breadth_first_traversal<
     'a,
-    Id: Copy + Eq + Hash + Display,
-    Registry: IdentifierRegistry<Id>,
-    Data: Clone + PartialEq,
-    WeightData: Clone + PartialEq,
+    Id: Copy + Eq + Hash + Display + 'a,
+    Data: Clone + PartialEq + 'a,
+    WeightData: Clone + PartialEq + 'a,
+    G: GraphStorage<Id, Data, WeightData>,
     V: GraphVisitor<'a, Id, Data, WeightData>,
 >(
-    graph: &'a Graph<Id, Data, WeightData, Registry>,
+    graph: &'a G,
     source: Id,
     visitor: &mut V,
-) {
-    assert!(
-        graph.vertices.contains_key(&source),
-        "The breadth-first search must begin on a vertex in the graph."
-    );
+) -> Result<(), GraphTraversalFailure<Id>> {
+    depth_limited_traversal(graph, source, usize::MAX, visitor).map(|_| ())
+}
+
+/// Depth-Limited Traversal.
+///
+/// Performs a breadth-first traversal on the graph from the given vertex, as
+/// in `breadth_first_traversal`, but does not visit vertices that are more
+/// than the given number of edges away from the source. A depth of zero visits
+/// only the source vertex.
+///
+/// Returns true if the depth limit cut the traversal short, i.e. some vertex
+/// at the maximum depth has an out neighbour that was not visited. Fails as
+/// `breadth_first_traversal` does.
+pub fn depth_limited_traversal<
+    'a,
+    Id: Copy + Eq + Hash + Display + 'a,
+    Data: Clone + PartialEq + 'a,
+    WeightData: Clone + PartialEq + 'a,
+    G: GraphStorage<Id, Data, WeightData>,
+    V: GraphVisitor<'a, Id, Data, WeightData>,
+>(
+    graph: &'a G,
+    source: Id,
+    max_depth: usize,
+    visitor: &mut V,
+) -> Result<bool, GraphTraversalFailure<Id>> {
+    depth_limited_discovery(graph, source, max_depth, &mut IgnoreDiscovery(visitor))
+}
+
+/// Breadth-First Discovery.
+///
+/// Performs a breadth-first traversal as `breadth_first_traversal` does, but
+/// hands the visitor the depth, predecessor and discovery index of every
+/// vertex it visits.
+pub fn breadth_first_discovery<
+    'a,
+    Id: Copy + Eq + Hash + Display + 'a,
+    Data: Clone + PartialEq + 'a,
+    WeightData: Clone + PartialEq + 'a,
+    G: GraphStorage<Id, Data, WeightData>,
+    V: TraversalVisitor<'a, Id, Data, WeightData>,
+>(
+    graph: &'a G,
+    source: Id,
+    visitor: &mut V,
+) -> Result<(), GraphTraversalFailure<Id>> {
+    depth_limited_discovery(graph, source, usize::MAX, visitor).map(|_| ())
+}
+
+/// Depth-Limited Discovery.
+///
+/// Performs a depth-limited traversal as `depth_limited_traversal` does, but
+/// hands the visitor the discovery metadata of every vertex it visits.
+pub fn depth_limited_discovery<
+    'a,
+    Id: Copy + Eq + Hash + Display + 'a,
+    Data: Clone + PartialEq + 'a,
+    WeightData: Clone + PartialEq + 'a,
+    G: GraphStorage<Id, Data, WeightData>,
+    V: TraversalVisitor<'a, Id, Data, WeightData>,
+>(
+    graph: &'a G,
+    source: Id,
+    max_depth: usize,
+    visitor: &mut V,
+) -> Result<bool, GraphTraversalFailure<Id>> {
+    if graph.vertex(source).is_none() {
+        return Err(GraphTraversalFailure::SourceNotFound(source));
+    }
 
     let mut transition_queue = VecDeque::new();
     let mut covered_vertices = HashSet::new();
+    let mut truncated = false;
+    let mut discovery_index = 0;
 
     visitor.reset();
 
-    transition_queue.push_back((None, source));
+    transition_queue.push_back((None, source, 0));
     covered_vertices.insert(source);
 
     loop {
@@ -343,27 +1393,231 @@ pub fn breadth_first_traversal<
             None => {
                 break;
             }
-            Some((maybe_edge_id, vertex_id)) => {
-                let vertex: &VertexDescriptor<Id, Data> = graph.vertices.get(&vertex_id).unwrap();
+            Some((maybe_edge_id, vertex_id, depth)) => {
+                let vertex: &VertexDescriptor<Id, Data> = graph
+                    .vertex(vertex_id)
+                    .ok_or(GraphTraversalFailure::VertexNotFound(vertex_id))?;
 
-                maybe_edge_id.map(|(from_vertex_id, edge_id): (Id, Id)| {
-                    let edge = graph.edges.get(&edge_id).unwrap();
+                if let Some((from_vertex_id, edge_id)) = maybe_edge_id {
+                    let edge = graph
+                        .edge(edge_id)
+                        .ok_or(GraphTraversalFailure::EdgeNotFound(edge_id))?;
                     visitor.visit_edge(from_vertex_id, edge, vertex_id)
-                });
+                }
 
-                visitor.visit_vertex(vertex);
+                let discovery = Discovery {
+                    depth,
+                    predecessor: maybe_edge_id.map(|(from_vertex_id, _)| from_vertex_id),
+                    index: discovery_index,
+                };
+                discovery_index += 1;
+                visitor.visit_vertex(vertex, discovery);
 
-                for (edge_id, to_vertex_id) in
-                    graph.forward_edges.get(&vertex_id).unwrap_or(&Vec::new())
-                {
-                    let new_transition = (Some((vertex_id, *edge_id)), *to_vertex_id);
+                if !visitor.should_expand(vertex) {
+                    continue;
+                }
+
+                for (edge_id, to_vertex_id) in graph.out_adjacency(vertex_id) {
+                    if covered_vertices.contains(to_vertex_id) {
+                        continue;
+                    }
 
-                    if !covered_vertices.contains(to_vertex_id) {
-                        covered_vertices.insert(*to_vertex_id);
-                        transition_queue.push_back(new_transition);
+                    if depth == max_depth {
+                        truncated = true;
+                        continue;
                     }
+
+                    let new_transition = (Some((vertex_id, *edge_id)), *to_vertex_id, depth + 1);
+
+                    covered_vertices.insert(*to_vertex_id);
+                    transition_queue.push_back(new_transition);
                 }
             }
         }
     }
+
+    Ok(truncated)
+}
+
+/// Iterative-Deepening Traversal.
+///
+/// Repeatedly performs depth-limited traversals from the given vertex with a
+/// depth limit of 0, 1, 2, ... up to (and including) the given maximum depth.
+/// After each pass the visitor is handed to the provided predicate; the driver
+/// stops as soon as it returns true and reports the depth of that pass.
+///
+/// Returns `None` if the predicate never held, either because the maximum
+/// depth was reached or because a pass covered every reachable vertex.
+pub fn iterative_deepening_traversal<
+    'a,
+    Id: Copy + Eq + Hash + Display + 'a,
+    Data: Clone + PartialEq + 'a,
+    WeightData: Clone + PartialEq + 'a,
+    G: GraphStorage<Id, Data, WeightData>,
+    V: GraphVisitor<'a, Id, Data, WeightData>,
+    F: FnMut(&V) -> bool,
+>(
+    graph: &'a G,
+    source: Id,
+    max_depth: usize,
+    visitor: &mut V,
+    mut is_done: F,
+) -> Result<Option<usize>, GraphTraversalFailure<Id>> {
+    for depth in 0..=max_depth {
+        let truncated = depth_limited_traversal(graph, source, depth, visitor)?;
+
+        if is_done(visitor) {
+            return Ok(Some(depth));
+        }
+
+        if !truncated {
+            break;
+        }
+    }
+
+    Ok(None)
+}
+
+/// Entry of the frontier in a best-first traversal.
+///
+/// Ordered so that the lowest priority is popped first from a max-heap, with
+/// ties broken by insertion order so that traversal is deterministic. Only
+/// priorities comparable with themselves enter the frontier, so the order is
+/// total for priorities that are totally ordered apart from NaN-like values.
+struct FrontierEntry<Id, Priority: PartialOrd> {
+    priority: Priority,
+    order: usize,
+    transition: (Option<(Id, Id)>, Id),
+}
+
+impl<Id, Priority: PartialOrd> PartialEq for FrontierEntry<Id, Priority> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<Id, Priority: PartialOrd> Eq for FrontierEntry<Id, Priority> {}
+
+impl<Id, Priority: PartialOrd> PartialOrd for FrontierEntry<Id, Priority> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Id, Priority: PartialOrd> Ord for FrontierEntry<Id, Priority> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .priority
+            .partial_cmp(&self.priority)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| other.order.cmp(&self.order))
+    }
+}
+
+/// Best-First Traversal.
+///
+/// Performs a traversal on the graph from the given vertex where the next
+/// vertex to visit is always the one with the lowest priority on the frontier.
+/// The priority of a vertex is computed by the provided function from the
+/// priority of the vertex it was reached from, the edge it was reached through
+/// and the vertex itself; for the source vertex, the first two are `None`.
+/// Ties are visited in the order they were discovered. A priority that cannot
+/// be compared with itself (e.g. NaN) fails the traversal with
+/// `GraphTraversalFailure::IncomparablePriority`.
+///
+/// A vertex may be reached by several edges before it is visited; only the
+/// transition with the lowest priority is visited, and the traversal of that
+/// edge happens just before the vertex is visited. Thus, accumulating edge
+/// weights in the priority function yields a uniform-cost search, while
+/// ignoring the parent priority yields a greedy search. Fails as
+/// `breadth_first_traversal` does.
+pub fn best_first_traversal<
+    'a,
+    Id: Copy + Eq + Hash + Display + 'a,
+    Data: Clone + PartialEq + 'a,
+    WeightData: Clone + PartialEq + 'a,
+    G: GraphStorage<Id, Data, WeightData>,
+    Priority: PartialOrd,
+    P: FnMut(
+        Option<&Priority>,
+        Option<&'a EdgeDescriptor<Id, WeightData>>,
+        &'a VertexDescriptor<Id, Data>,
+    ) -> Priority,
+    V: GraphVisitor<'a, Id, Data, WeightData>,
+>(
+    graph: &'a G,
+    source: Id,
+    mut priority_fn: P,
+    visitor: &mut V,
+) -> Result<(), GraphTraversalFailure<Id>> {
+    let source_vertex = graph
+        .vertex(source)
+        .ok_or(GraphTraversalFailure::SourceNotFound(source))?;
+
+    let mut frontier = BinaryHeap::new();
+    let mut visited_vertices = HashSet::new();
+    let mut discovered = 0;
+
+    let checked = |priority: Priority, vertex_id: Id| match priority.partial_cmp(&priority) {
+        Some(_) => Ok(priority),
+        None => Err(GraphTraversalFailure::IncomparablePriority(vertex_id)),
+    };
+
+    visitor.reset();
+
+    frontier.push(FrontierEntry {
+        priority: checked(priority_fn(None, None, source_vertex), source)?,
+        order: discovered,
+        transition: (None, source),
+    });
+
+    while let Some(entry) = frontier.pop() {
+        let (maybe_edge_id, vertex_id) = entry.transition;
+
+        if !visited_vertices.insert(vertex_id) {
+            continue;
+        }
+
+        let vertex: &VertexDescriptor<Id, Data> = graph
+            .vertex(vertex_id)
+            .ok_or(GraphTraversalFailure::VertexNotFound(vertex_id))?;
+
+        if let Some((from_vertex_id, edge_id)) = maybe_edge_id {
+            let edge = graph
+                .edge(edge_id)
+                .ok_or(GraphTraversalFailure::EdgeNotFound(edge_id))?;
+            visitor.visit_edge(from_vertex_id, edge, vertex_id)
+        }
+
+        visitor.visit_vertex(vertex);
+
+        if !visitor.should_expand(vertex) {
+            continue;
+        }
+
+        for (edge_id, to_vertex_id) in graph.out_adjacency(vertex_id) {
+            if visited_vertices.contains(to_vertex_id) {
+                continue;
+            }
+
+            let edge = graph
+                .edge(*edge_id)
+                .ok_or(GraphTraversalFailure::EdgeNotFound(*edge_id))?;
+            let to_vertex = graph
+                .vertex(*to_vertex_id)
+                .ok_or(GraphTraversalFailure::VertexNotFound(*to_vertex_id))?;
+
+            discovered += 1;
+            frontier.push(FrontierEntry {
+                priority: checked(
+                    priority_fn(Some(&entry.priority), Some(edge), to_vertex),
+                    *to_vertex_id,
+                )?,
+                order: discovered,
+                transition: (Some((vertex_id, *edge_id)), *to_vertex_id),
+            });
+        }
+    }
+
+    Ok(())
 }
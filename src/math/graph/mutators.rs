@@ -33,32 +33,53 @@ SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use crate::math::graph::*;
 
+/// Vertex Addition Mutator.
+///
+/// Single-use mutator that adds a vertex with the given data to a graph. The
+/// identifier of the new vertex is available once the mutator is applied.
 pub struct GraphVertexAdditionMutator<Id: Copy + Eq + Hash + Display, Data: Clone + PartialEq> {
     vertex_id: Option<Id>,
     vertex_data: Option<Data>,
 }
 
+/// Edge Addition Mutator.
+///
+/// Single-use mutator that adds an edge with the given data between two
+/// vertices of a graph. The identifier of the new edge is available once the
+/// mutator is applied.
 pub struct GraphEdgeAdditionMutator<Id: Copy + Eq + Hash + Display, Data: Clone + PartialEq> {
     edge_id: Option<Id>,
     edge_desc: Option<(Id, Data, Id)>,
+    replaced_data: Option<Data>,
 }
 
 impl<Id: Copy + Eq + Hash + Display, Data: Clone + PartialEq> GraphVertexAdditionMutator<Id, Data> {
-    fn new(data: Data) -> Self {
+    pub fn new(data: Data) -> Self {
         GraphVertexAdditionMutator {
             vertex_id: None,
             vertex_data: Some(data),
         }
     }
+
+    /// Returns the identifier of the added vertex, if the mutator was applied.
+    pub fn vertex_id(&self) -> Option<Id> {
+        self.vertex_id
+    }
 }
 
 impl<Id: Copy + Eq + Hash + Display, Data: Clone + PartialEq> GraphEdgeAdditionMutator<Id, Data> {
-    fn new(vfrom: Id, data: Data, vto: Id) -> Self {
+    pub fn new(vfrom: Id, data: Data, vto: Id) -> Self {
         GraphEdgeAdditionMutator {
             edge_id: None,
             edge_desc: Some((vfrom, data, vto)),
+            replaced_data: None,
         }
     }
+
+    /// Returns the identifier of the added edge, if the mutator was applied.
+    pub fn edge_id(&self) -> Option<Id> {
+        self.edge_id
+    }
 }
 
 impl<
@@ -66,36 +87,61 @@ impl<
         Data: Clone + PartialEq,
         WeightData: Clone + PartialEq,
         Registry: IdentifierRegistry<Id>,
-    > GraphMutator<Id, Data, WeightData, Registry> for GraphVertexAdditionMutator<Id, Data>
+        S: BuildHasher + Clone + Default,
+    > GraphMutator<Id, Data, WeightData, Registry, S> for GraphVertexAdditionMutator<Id, Data>
 {
     fn mutate(
         &mut self,
-        graph: Graph<Id, Data, WeightData, Registry>,
-    ) -> Graph<Id, Data, WeightData, Registry> {
-        let data = self
-            .vertex_data
-            .take()
-            .expect("Vertex addition mutator has already been used.");
+        graph: Graph<Id, Data, WeightData, Registry, S>,
+    ) -> Graph<Id, Data, WeightData, Registry, S> {
+        self.try_mutate(graph)
+            .unwrap_or_else(|(_, failure)| panic!("Failed to add vertex. {failure}"))
+    }
+
+    fn try_mutate(
+        &mut self,
+        graph: Graph<Id, Data, WeightData, Registry, S>,
+    ) -> GraphMutationResult<Id, Data, WeightData, Registry, S> {
+        let mut graph = graph;
+        let data = match self.vertex_data.take() {
+            Some(data) => data,
+            None => return Err((graph, GraphMutationFailure::MutatorAlreadyUsed)),
+        };
 
-        let mut vertex_registry = graph.vertex_id_registry;
-        let mut vertices = graph.vertices;
+        let new_id = match graph.vertex_id_registry.acquire_id() {
+            Ok(new_id) => new_id,
+            Err(failure) => {
+                self.vertex_data = Some(data);
+                return Err((graph, failure.into()));
+            }
+        };
 
-        let new_id = vertex_registry
-            .acquire_id()
-            .expect("Unable to acquire new identifier for new vertex.");
+        graph.vertices.insert(new_id, make_vertex(new_id, data));
         self.vertex_id = Some(new_id);
+        Ok(graph)
+    }
 
-        let vertex = make_vertex(new_id, data);
-        vertices.insert(new_id, vertex);
+    fn undo(
+        &mut self,
+        graph: Graph<Id, Data, WeightData, Registry, S>,
+    ) -> Graph<Id, Data, WeightData, Registry, S> {
+        let mut graph = graph;
+        let vertex_id = self
+            .vertex_id
+            .take()
+            .expect("Vertex addition was not applied, so it cannot be undone.");
 
-        Graph {
-            vertex_id_registry: vertex_registry,
-            edge_id_registry: graph.edge_id_registry,
-            vertices: vertices,
-            edges: graph.edges,
-            forward_edges: graph.forward_edges,
-            backward_edges: graph.backward_edges,
-        }
+        let vertex = graph
+            .vertices
+            .remove(&vertex_id)
+            .expect("Graph is ill-formed; the added vertex is missing.");
+        graph
+            .vertex_id_registry
+            .release_id(vertex_id)
+            .expect("Graph is ill-formed; the added vertex id was already released.");
+
+        self.vertex_data = Some(vertex.data().clone());
+        graph
     }
 }
 
@@ -104,53 +150,607 @@ impl<
         Data: Clone + PartialEq,
         WeightData: Clone + PartialEq,
         Registry: IdentifierRegistry<Id>,
-    > GraphMutator<Id, Data, WeightData, Registry> for GraphEdgeAdditionMutator<Id, WeightData>
+        S: BuildHasher + Clone + Default,
+    > GraphMutator<Id, Data, WeightData, Registry, S> for GraphEdgeAdditionMutator<Id, WeightData>
 {
     fn mutate(
         &mut self,
-        graph: Graph<Id, Data, WeightData, Registry>,
-    ) -> Graph<Id, Data, WeightData, Registry> {
-        let (vertex_from_id, data, vertex_to_id) = self
-            .edge_desc
-            .take()
-            .expect("Edge addition mutator has already been used.");
+        graph: Graph<Id, Data, WeightData, Registry, S>,
+    ) -> Graph<Id, Data, WeightData, Registry, S> {
+        self.try_mutate(graph)
+            .unwrap_or_else(|(_, failure)| panic!("Failed to add edge. {failure}"))
+    }
 
-        let mut edge_registry = graph.edge_id_registry;
-        let mut edges = graph.edges;
-        let mut forward_edges = graph.forward_edges;
-        let mut backward_edges = graph.backward_edges;
+    fn try_mutate(
+        &mut self,
+        graph: Graph<Id, Data, WeightData, Registry, S>,
+    ) -> GraphMutationResult<Id, Data, WeightData, Registry, S> {
+        let mut graph = graph;
+        let (vertex_from_id, data, vertex_to_id) = match self.edge_desc.take() {
+            Some(edge_desc) => edge_desc,
+            None => return Err((graph, GraphMutationFailure::MutatorAlreadyUsed)),
+        };
 
-        let new_id = edge_registry
-            .acquire_id()
-            .expect("Unable to acquire new identifier for new edge.");
-        self.edge_id = Some(new_id);
+        for vertex_id in [vertex_from_id, vertex_to_id] {
+            if !graph.vertices.contains_key(&vertex_id) {
+                self.edge_desc = Some((vertex_from_id, data, vertex_to_id));
+                return Err((graph, GraphMutationFailure::VertexNotFound(vertex_id)));
+            }
+        }
 
-        let edge = make_edge(new_id, data);
+        if graph.parallel_edge_policy != ParallelEdgePolicy::Allow {
+            if let Some(existing_id) = graph
+                .get_edge_between(vertex_from_id, vertex_to_id)
+                .map(|edge| *edge.id())
+            {
+                if graph.parallel_edge_policy == ParallelEdgePolicy::Reject {
+                    self.edge_desc = Some((vertex_from_id, data, vertex_to_id));
+                    return Err((
+                        graph,
+                        GraphMutationFailure::ParallelEdge(vertex_from_id, vertex_to_id),
+                    ));
+                }
 
-        edges.insert(new_id, edge);
-        forward_edges
+                let edge = graph
+                    .edges
+                    .get_mut(&existing_id)
+                    .expect("Graph is ill-formed; an adjacent edge is missing.");
+                self.replaced_data = Some(std::mem::replace(edge.data_mut(), data));
+                self.edge_id = Some(existing_id);
+
+                return Ok(graph);
+            }
+        }
+
+        let new_id = match graph.edge_id_registry.acquire_id() {
+            Ok(new_id) => new_id,
+            Err(failure) => {
+                self.edge_desc = Some((vertex_from_id, data, vertex_to_id));
+                return Err((graph, failure.into()));
+            }
+        };
+
+        graph.edges.insert(new_id, make_edge(new_id, data));
+        graph
+            .forward_edges
             .entry(vertex_from_id)
-            .or_insert(Vec::new())
-            .push((new_id, vertex_to_id.clone()));
-        backward_edges
+            .or_default()
+            .push((new_id, vertex_to_id));
+        graph
+            .backward_edges
             .entry(vertex_to_id)
-            .or_insert(Vec::new())
-            .push((new_id, vertex_from_id.clone()));
+            .or_default()
+            .push((new_id, vertex_from_id));
+        graph
+            .edge_endpoints
+            .insert(new_id, (vertex_from_id, vertex_to_id));
+
+        self.edge_id = Some(new_id);
+        Ok(graph)
+    }
+
+    fn undo(
+        &mut self,
+        graph: Graph<Id, Data, WeightData, Registry, S>,
+    ) -> Graph<Id, Data, WeightData, Registry, S> {
+        let mut graph = graph;
+        let edge_id = self
+            .edge_id
+            .take()
+            .expect("Edge addition was not applied, so it cannot be undone.");
+        let (vertex_from_id, vertex_to_id) = *graph
+            .edge_endpoints
+            .get(&edge_id)
+            .expect("Graph is ill-formed; the added edge has no endpoints.");
+
+        // An edge that replaced the data of a parallel one only restores it.
+        if let Some(replaced_data) = self.replaced_data.take() {
+            let edge = graph
+                .edges
+                .get_mut(&edge_id)
+                .expect("Graph is ill-formed; the replaced edge is missing.");
+            let data = std::mem::replace(edge.data_mut(), replaced_data);
+            self.edge_desc = Some((vertex_from_id, data, vertex_to_id));
+            return graph;
+        }
+
+        let edge = graph
+            .edges
+            .remove(&edge_id)
+            .expect("Graph is ill-formed; the added edge is missing.");
+        graph.edge_endpoints.remove(&edge_id);
+        for (adjacency, vertex_id) in [
+            (&mut graph.forward_edges, vertex_from_id),
+            (&mut graph.backward_edges, vertex_to_id),
+        ] {
+            let adjacent = adjacency
+                .get_mut(&vertex_id)
+                .expect("Graph is ill-formed; the added edge is not adjacent.");
+            adjacent.retain(|(adjacent_edge_id, _)| *adjacent_edge_id != edge_id);
+            if adjacent.is_empty() {
+                adjacency.remove(&vertex_id);
+            }
+        }
+        graph
+            .edge_id_registry
+            .release_id(edge_id)
+            .expect("Graph is ill-formed; the added edge id was already released.");
+
+        self.edge_desc = Some((vertex_from_id, edge.data().clone(), vertex_to_id));
+        graph
+    }
+}
+
+/// Vertex Mapping Mutator.
+///
+/// Single-use mutator that replaces the data of a vertex with the result of
+/// applying the given function to its current data.
+pub struct GraphVertexMappingMutator<Id: Copy + Eq + Hash + Display, Data: Clone + PartialEq, F> {
+    vertex_id: Id,
+    map: Option<F>,
+    previous_data: Option<Data>,
+}
+
+/// Edge Mapping Mutator.
+///
+/// Single-use mutator that replaces the data of an edge with the result of
+/// applying the given function to its current data.
+pub struct GraphEdgeMappingMutator<Id: Copy + Eq + Hash + Display, Data: Clone + PartialEq, F> {
+    edge_id: Id,
+    map: Option<F>,
+    previous_data: Option<Data>,
+}
+
+impl<Id: Copy + Eq + Hash + Display, Data: Clone + PartialEq, F: FnOnce(&Data) -> Data>
+    GraphVertexMappingMutator<Id, Data, F>
+{
+    pub fn new(vertex_id: Id, map: F) -> Self {
+        GraphVertexMappingMutator {
+            vertex_id,
+            map: Some(map),
+            previous_data: None,
+        }
+    }
+}
+
+impl<Id: Copy + Eq + Hash + Display, Data: Clone + PartialEq, F: FnOnce(&Data) -> Data>
+    GraphEdgeMappingMutator<Id, Data, F>
+{
+    pub fn new(edge_id: Id, map: F) -> Self {
+        GraphEdgeMappingMutator {
+            edge_id,
+            map: Some(map),
+            previous_data: None,
+        }
+    }
+}
+
+impl<
+        Id: Copy + Eq + Hash + Display,
+        Data: Clone + PartialEq,
+        WeightData: Clone + PartialEq,
+        Registry: IdentifierRegistry<Id>,
+        S: BuildHasher + Clone + Default,
+        F: FnOnce(&Data) -> Data,
+    > GraphMutator<Id, Data, WeightData, Registry, S> for GraphVertexMappingMutator<Id, Data, F>
+{
+    fn mutate(
+        &mut self,
+        graph: Graph<Id, Data, WeightData, Registry, S>,
+    ) -> Graph<Id, Data, WeightData, Registry, S> {
+        self.try_mutate(graph)
+            .unwrap_or_else(|(_, failure)| panic!("Failed to map vertex. {failure}"))
+    }
+
+    fn try_mutate(
+        &mut self,
+        graph: Graph<Id, Data, WeightData, Registry, S>,
+    ) -> GraphMutationResult<Id, Data, WeightData, Registry, S> {
+        let mut graph = graph;
+        let map = match self.map.take() {
+            Some(map) => map,
+            None => return Err((graph, GraphMutationFailure::MutatorAlreadyUsed)),
+        };
+
+        match graph.vertices.get_mut(&self.vertex_id) {
+            Some(vertex) => {
+                let data = map(vertex.data());
+                self.previous_data = Some(std::mem::replace(vertex.data_mut(), data));
+            }
+            None => {
+                self.map = Some(map);
+                return Err((graph, GraphMutationFailure::VertexNotFound(self.vertex_id)));
+            }
+        }
+
+        Ok(graph)
+    }
+
+    fn undo(
+        &mut self,
+        graph: Graph<Id, Data, WeightData, Registry, S>,
+    ) -> Graph<Id, Data, WeightData, Registry, S> {
+        let mut graph = graph;
+        let previous_data = self
+            .previous_data
+            .take()
+            .expect("Vertex mapping was not applied, so it cannot be undone.");
+
+        let vertex = graph
+            .vertices
+            .get_mut(&self.vertex_id)
+            .expect("Graph is ill-formed; the mapped vertex is missing.");
+        *vertex.data_mut() = previous_data;
+        graph
+    }
+}
+
+impl<
+        Id: Copy + Eq + Hash + Display,
+        Data: Clone + PartialEq,
+        WeightData: Clone + PartialEq,
+        Registry: IdentifierRegistry<Id>,
+        S: BuildHasher + Clone + Default,
+        F: FnOnce(&WeightData) -> WeightData,
+    > GraphMutator<Id, Data, WeightData, Registry, S>
+    for GraphEdgeMappingMutator<Id, WeightData, F>
+{
+    fn mutate(
+        &mut self,
+        graph: Graph<Id, Data, WeightData, Registry, S>,
+    ) -> Graph<Id, Data, WeightData, Registry, S> {
+        self.try_mutate(graph)
+            .unwrap_or_else(|(_, failure)| panic!("Failed to map edge. {failure}"))
+    }
+
+    fn try_mutate(
+        &mut self,
+        graph: Graph<Id, Data, WeightData, Registry, S>,
+    ) -> GraphMutationResult<Id, Data, WeightData, Registry, S> {
+        let mut graph = graph;
+        let map = match self.map.take() {
+            Some(map) => map,
+            None => return Err((graph, GraphMutationFailure::MutatorAlreadyUsed)),
+        };
+
+        match graph.edges.get_mut(&self.edge_id) {
+            Some(edge) => {
+                let data = map(edge.data());
+                self.previous_data = Some(std::mem::replace(edge.data_mut(), data));
+            }
+            None => {
+                self.map = Some(map);
+                return Err((graph, GraphMutationFailure::EdgeNotFound(self.edge_id)));
+            }
+        }
+
+        Ok(graph)
+    }
+
+    fn undo(
+        &mut self,
+        graph: Graph<Id, Data, WeightData, Registry, S>,
+    ) -> Graph<Id, Data, WeightData, Registry, S> {
+        let mut graph = graph;
+        let previous_data = self
+            .previous_data
+            .take()
+            .expect("Edge mapping was not applied, so it cannot be undone.");
+
+        let edge = graph
+            .edges
+            .get_mut(&self.edge_id)
+            .expect("Graph is ill-formed; the mapped edge is missing.");
+        *edge.data_mut() = previous_data;
+        graph
+    }
+}
+
+/// Mutator Chain.
+///
+/// Applies one mutator and then another to its output. Built with
+/// `GraphMutator::then`.
+pub struct Then<First, Second> {
+    first: First,
+    second: Second,
+}
+
+impl<First, Second> Then<First, Second> {
+    pub fn new(first: First, second: Second) -> Self {
+        Then { first, second }
+    }
+}
+
+impl<
+        Id: Copy + Eq + Hash + Display,
+        Data: Clone + PartialEq,
+        WeightData: Clone + PartialEq,
+        Registry: IdentifierRegistry<Id>,
+        S: BuildHasher + Clone + Default,
+        First: GraphMutator<Id, Data, WeightData, Registry, S>,
+        Second: GraphMutator<Id, Data, WeightData, Registry, S>,
+    > GraphMutator<Id, Data, WeightData, Registry, S> for Then<First, Second>
+{
+    fn mutate(
+        &mut self,
+        graph: Graph<Id, Data, WeightData, Registry, S>,
+    ) -> Graph<Id, Data, WeightData, Registry, S> {
+        self.second.mutate(self.first.mutate(graph))
+    }
+
+    fn try_mutate(
+        &mut self,
+        graph: Graph<Id, Data, WeightData, Registry, S>,
+    ) -> GraphMutationResult<Id, Data, WeightData, Registry, S> {
+        let graph = self.first.try_mutate(graph)?;
+        self.second
+            .try_mutate(graph)
+            .map_err(|(graph, failure)| (self.first.undo(graph), failure))
+    }
+
+    fn undo(
+        &mut self,
+        graph: Graph<Id, Data, WeightData, Registry, S>,
+    ) -> Graph<Id, Data, WeightData, Registry, S> {
+        self.first.undo(self.second.undo(graph))
+    }
+}
+
+/// Mutator Repetition.
+///
+/// Applies a mutator a fixed number of times in a row. Built with
+/// `GraphMutator::repeat`; only meaningful for mutators that can be applied
+/// more than once.
+pub struct Repeat<M> {
+    mutator: M,
+    times: usize,
+}
+
+impl<M> Repeat<M> {
+    pub fn new(mutator: M, times: usize) -> Self {
+        Repeat { mutator, times }
+    }
+}
+
+impl<
+        Id: Copy + Eq + Hash + Display,
+        Data: Clone + PartialEq,
+        WeightData: Clone + PartialEq,
+        Registry: IdentifierRegistry<Id>,
+        S: BuildHasher + Clone + Default,
+        M: GraphMutator<Id, Data, WeightData, Registry, S>,
+    > GraphMutator<Id, Data, WeightData, Registry, S> for Repeat<M>
+{
+    fn mutate(
+        &mut self,
+        graph: Graph<Id, Data, WeightData, Registry, S>,
+    ) -> Graph<Id, Data, WeightData, Registry, S> {
+        (0..self.times).fold(graph, |graph, _| self.mutator.mutate(graph))
+    }
+
+    fn try_mutate(
+        &mut self,
+        graph: Graph<Id, Data, WeightData, Registry, S>,
+    ) -> GraphMutationResult<Id, Data, WeightData, Registry, S> {
+        let mut graph = graph;
+        for applied in 0..self.times {
+            match self.mutator.try_mutate(graph) {
+                Ok(new_graph) => graph = new_graph,
+                Err((graph, failure)) => {
+                    let graph = (0..applied).fold(graph, |graph, _| self.mutator.undo(graph));
+                    return Err((graph, failure));
+                }
+            }
+        }
+
+        Ok(graph)
+    }
+
+    fn undo(
+        &mut self,
+        graph: Graph<Id, Data, WeightData, Registry, S>,
+    ) -> Graph<Id, Data, WeightData, Registry, S> {
+        (0..self.times).fold(graph, |graph, _| self.mutator.undo(graph))
+    }
+}
+
+/// Mutator Pipeline.
+///
+/// Owns a sequence of (possibly different kinds of) mutators and applies them
+/// in order, each to the output of the previous one. A pipeline is itself a
+/// mutator, so pipelines can be nested, chained and repeated.
+pub struct MutatorPipeline<
+    'm,
+    Id: Copy + Eq + Hash + Display,
+    Data: Clone + PartialEq,
+    WeightData: Clone + PartialEq,
+    Registry: IdentifierRegistry<Id>,
+    S: BuildHasher + Clone + Default = RandomState,
+> {
+    mutators: Vec<Box<dyn GraphMutator<Id, Data, WeightData, Registry, S> + 'm>>,
+}
 
-        Graph {
-            vertex_id_registry: graph.vertex_id_registry,
-            edge_id_registry: edge_registry,
-            vertices: graph.vertices,
-            edges: edges,
-            forward_edges: forward_edges,
-            backward_edges: backward_edges,
+impl<
+        'm,
+        Id: Copy + Eq + Hash + Display,
+        Data: Clone + PartialEq,
+        WeightData: Clone + PartialEq,
+        Registry: IdentifierRegistry<Id>,
+        S: BuildHasher + Clone + Default,
+    > MutatorPipeline<'m, Id, Data, WeightData, Registry, S>
+{
+    /// Creates an empty pipeline, which leaves graphs unchanged.
+    pub fn new() -> Self {
+        MutatorPipeline {
+            mutators: Vec::new(),
         }
     }
+
+    /// Appends a mutator to the end of the pipeline.
+    pub fn with<M: GraphMutator<Id, Data, WeightData, Registry, S> + 'm>(
+        mut self,
+        mutator: M,
+    ) -> Self {
+        self.mutators.push(Box::new(mutator));
+        self
+    }
+}
+
+impl<
+        'm,
+        Id: Copy + Eq + Hash + Display,
+        Data: Clone + PartialEq,
+        WeightData: Clone + PartialEq,
+        Registry: IdentifierRegistry<Id>,
+        S: BuildHasher + Clone + Default,
+    > Default for MutatorPipeline<'m, Id, Data, WeightData, Registry, S>
+{
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
+impl<
+        'm,
+        Id: Copy + Eq + Hash + Display,
+        Data: Clone + PartialEq,
+        WeightData: Clone + PartialEq,
+        Registry: IdentifierRegistry<Id>,
+        S: BuildHasher + Clone + Default,
+    > GraphMutator<Id, Data, WeightData, Registry, S>
+    for MutatorPipeline<'m, Id, Data, WeightData, Registry, S>
+{
+    fn mutate(
+        &mut self,
+        graph: Graph<Id, Data, WeightData, Registry, S>,
+    ) -> Graph<Id, Data, WeightData, Registry, S> {
+        self.mutators
+            .iter_mut()
+            .fold(graph, |graph, mutator| mutator.mutate(graph))
+    }
+
+    fn try_mutate(
+        &mut self,
+        graph: Graph<Id, Data, WeightData, Registry, S>,
+    ) -> GraphMutationResult<Id, Data, WeightData, Registry, S> {
+        let mut graph = graph;
+        for applied in 0..self.mutators.len() {
+            match self.mutators[applied].try_mutate(graph) {
+                Ok(new_graph) => graph = new_graph,
+                Err((graph, failure)) => {
+                    let graph = self.mutators[..applied]
+                        .iter_mut()
+                        .rev()
+                        .fold(graph, |graph, mutator| mutator.undo(graph));
+                    return Err((graph, failure));
+                }
+            }
+        }
+
+        Ok(graph)
+    }
+
+    fn undo(
+        &mut self,
+        graph: Graph<Id, Data, WeightData, Registry, S>,
+    ) -> Graph<Id, Data, WeightData, Registry, S> {
+        self.mutators
+            .iter_mut()
+            .rev()
+            .fold(graph, |graph, mutator| mutator.undo(graph))
+    }
+}
+
+/// Graph Transaction.
+///
+/// Records a sequence of mutators and applies them to a graph atomically: if
+/// any mutator fails, the mutators applied before it are undone, in reverse
+/// order, so the graph holds the same elements, and its registries the same
+/// identifiers in use, as before the transaction was applied. The mutators are
+/// borrowed so that their results (e.g. the identifiers of added vertices) can
+/// be inspected afterwards.
+pub struct GraphTransaction<
+    'm,
+    Id: Copy + Eq + Hash + Display,
+    Data: Clone + PartialEq,
+    WeightData: Clone + PartialEq,
+    Registry: IdentifierRegistry<Id>,
+    S: BuildHasher + Clone + Default = RandomState,
+> {
+    mutators: Vec<&'m mut dyn GraphMutator<Id, Data, WeightData, Registry, S>>,
+}
+
+impl<
+        'm,
+        Id: Copy + Eq + Hash + Display,
+        Data: Clone + PartialEq,
+        WeightData: Clone + PartialEq,
+        Registry: IdentifierRegistry<Id>,
+        S: BuildHasher + Clone + Default,
+    > GraphTransaction<'m, Id, Data, WeightData, Registry, S>
+{
+    /// Creates an empty transaction.
+    pub fn new() -> Self {
+        GraphTransaction {
+            mutators: Vec::new(),
+        }
+    }
+
+    /// Appends a mutator to the transaction.
+    pub fn with(
+        mut self,
+        mutator: &'m mut dyn GraphMutator<Id, Data, WeightData, Registry, S>,
+    ) -> Self {
+        self.mutators.push(mutator);
+        self
+    }
+
+    /// Applies the recorded mutators, in order, to the given graph.
+    ///
+    /// Returns the failure of the first mutator that failed, in which case the
+    /// mutators applied before it are undone, so none reports an identifier
+    /// that is no longer in the graph.
+    pub fn apply(
+        mut self,
+        graph: &mut Graph<Id, Data, WeightData, Registry, S>,
+    ) -> Result<(), GraphMutationFailure<Id>> {
+        let empty_graph = Graph::new(Registry::null_registry(), Registry::null_registry());
+        let mut current_graph = std::mem::replace(graph, empty_graph);
+
+        for applied in 0..self.mutators.len() {
+            match self.mutators[applied].try_mutate(current_graph) {
+                Ok(new_graph) => current_graph = new_graph,
+                Err((failed_graph, failure)) => {
+                    *graph = self.mutators[..applied]
+                        .iter_mut()
+                        .rev()
+                        .fold(failed_graph, |graph, mutator| mutator.undo(graph));
+                    return Err(failure);
+                }
+            }
+        }
+
+        *graph = current_graph;
+        Ok(())
+    }
+}
+
+impl<
+        'm,
+        Id: Copy + Eq + Hash + Display,
+        Data: Clone + PartialEq,
+        WeightData: Clone + PartialEq,
+        Registry: IdentifierRegistry<Id>,
+        S: BuildHasher + Clone + Default,
+    > Default for GraphTransaction<'m, Id, Data, WeightData, Registry, S>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 /// Adds a vertex into the graph.
-/// 
+///
 /// Mutates the given graph (in-place) by adding a new vertex with the given
 /// data and returns the id associated with the new vertex.
 pub fn add_vertex<
@@ -158,12 +758,13 @@ pub fn add_vertex<
     Data: Clone + PartialEq,
     WeightData: Clone + PartialEq,
     Registry: IdentifierRegistry<Id>,
+    S: BuildHasher + Clone + Default,
 >(
-    graph: &mut Graph<Id, Data, WeightData, Registry>,
+    graph: &mut Graph<Id, Data, WeightData, Registry, S>,
     data: Data,
 ) -> Id {
     let empty_graph = Graph::new(Registry::null_registry(), Registry::null_registry());
-    let mut current_graph: Graph<Id, Data, WeightData, Registry> =
+    let mut current_graph: Graph<Id, Data, WeightData, Registry, S> =
         std::mem::replace(graph, empty_graph);
 
     let mut vertex_adder = GraphVertexAdditionMutator::new(data);
@@ -178,7 +779,7 @@ pub fn add_vertex<
 }
 
 /// Adds a edge into the graph.
-/// 
+///
 /// Mutates the given graph (in-place) by adding a new edge between the two
 /// vertices (of the given ids) and with the given data. The method returns the
 /// id associated with the new edge.
@@ -187,14 +788,15 @@ pub fn add_edge<
     Data: Clone + PartialEq,
     WeightData: Clone + PartialEq,
     Registry: IdentifierRegistry<Id>,
+    S: BuildHasher + Clone + Default,
 >(
-    graph: &mut Graph<Id, Data, WeightData, Registry>,
+    graph: &mut Graph<Id, Data, WeightData, Registry, S>,
     vertex_from: Id,
     vertex_to: Id,
     data: WeightData,
 ) -> Id {
     let empty_graph = Graph::new(Registry::null_registry(), Registry::null_registry());
-    let mut current_graph: Graph<Id, Data, WeightData, Registry> =
+    let mut current_graph: Graph<Id, Data, WeightData, Registry, S> =
         std::mem::replace(graph, empty_graph);
 
     let mut edge_adder = GraphEdgeAdditionMutator::new(vertex_from, data, vertex_to);
@@ -207,3 +809,169 @@ pub fn add_edge<
         .take()
         .expect("Failed to insert edge in graph for an unknown reason.")
 }
+
+/// Maps the data of a vertex in the graph.
+///
+/// Mutates the given graph (in-place) by replacing the data of the vertex with
+/// the given id by the result of applying the given function to it.
+pub fn map_vertex<
+    Id: Copy + Eq + Hash + Display,
+    Data: Clone + PartialEq,
+    WeightData: Clone + PartialEq,
+    Registry: IdentifierRegistry<Id>,
+    S: BuildHasher + Clone + Default,
+    F: FnOnce(&Data) -> Data,
+>(
+    graph: &mut Graph<Id, Data, WeightData, Registry, S>,
+    vertex_id: Id,
+    map: F,
+) {
+    let empty_graph = Graph::new(Registry::null_registry(), Registry::null_registry());
+    let mut current_graph: Graph<Id, Data, WeightData, Registry, S> =
+        std::mem::replace(graph, empty_graph);
+
+    let mut vertex_mapper = GraphVertexMappingMutator::new(vertex_id, map);
+    current_graph = vertex_mapper.mutate(current_graph);
+
+    let _ = std::mem::replace(graph, current_graph);
+}
+
+/// Maps the data of an edge in the graph.
+///
+/// Mutates the given graph (in-place) by replacing the data of the edge with
+/// the given id by the result of applying the given function to it.
+pub fn map_edge<
+    Id: Copy + Eq + Hash + Display,
+    Data: Clone + PartialEq,
+    WeightData: Clone + PartialEq,
+    Registry: IdentifierRegistry<Id>,
+    S: BuildHasher + Clone + Default,
+    F: FnOnce(&WeightData) -> WeightData,
+>(
+    graph: &mut Graph<Id, Data, WeightData, Registry, S>,
+    edge_id: Id,
+    map: F,
+) {
+    let empty_graph = Graph::new(Registry::null_registry(), Registry::null_registry());
+    let mut current_graph: Graph<Id, Data, WeightData, Registry, S> =
+        std::mem::replace(graph, empty_graph);
+
+    let mut edge_mapper = GraphEdgeMappingMutator::new(edge_id, map);
+    current_graph = edge_mapper.mutate(current_graph);
+
+    let _ = std::mem::replace(graph, current_graph);
+}
+
+/// Adds a vertex into the graph, or fails.
+///
+/// As `add_vertex`, but reports failures instead of panicking, in which case
+/// the graph is left unchanged.
+pub fn try_add_vertex<
+    Id: Copy + Eq + Hash + Display,
+    Data: Clone + PartialEq,
+    WeightData: Clone + PartialEq,
+    Registry: IdentifierRegistry<Id>,
+    S: BuildHasher + Clone + Default,
+>(
+    graph: &mut Graph<Id, Data, WeightData, Registry, S>,
+    data: Data,
+) -> Result<Id, GraphMutationFailure<Id>> {
+    let empty_graph = Graph::new(Registry::null_registry(), Registry::null_registry());
+    let current_graph = std::mem::replace(graph, empty_graph);
+
+    let mut vertex_adder = GraphVertexAdditionMutator::new(data);
+    match vertex_adder.try_mutate(current_graph) {
+        Ok(new_graph) => {
+            *graph = new_graph;
+            Ok(vertex_adder
+                .vertex_id
+                .expect("Failed to insert vertex in graph for an unknown reason."))
+        }
+        Err((old_graph, failure)) => {
+            *graph = old_graph;
+            Err(failure)
+        }
+    }
+}
+
+/// Adds an edge into the graph, or fails.
+///
+/// As `add_edge`, but reports failures (e.g. a missing endpoint) instead of
+/// panicking, in which case the graph is left unchanged.
+pub fn try_add_edge<
+    Id: Copy + Eq + Hash + Display,
+    Data: Clone + PartialEq,
+    WeightData: Clone + PartialEq,
+    Registry: IdentifierRegistry<Id>,
+    S: BuildHasher + Clone + Default,
+>(
+    graph: &mut Graph<Id, Data, WeightData, Registry, S>,
+    vertex_from: Id,
+    vertex_to: Id,
+    data: WeightData,
+) -> Result<Id, GraphMutationFailure<Id>> {
+    let empty_graph = Graph::new(Registry::null_registry(), Registry::null_registry());
+    let current_graph = std::mem::replace(graph, empty_graph);
+
+    let mut edge_adder = GraphEdgeAdditionMutator::new(vertex_from, data, vertex_to);
+    match edge_adder.try_mutate(current_graph) {
+        Ok(new_graph) => {
+            *graph = new_graph;
+            Ok(edge_adder
+                .edge_id
+                .expect("Failed to insert edge in graph for an unknown reason."))
+        }
+        Err((old_graph, failure)) => {
+            *graph = old_graph;
+            Err(failure)
+        }
+    }
+}
+
+/// Maps the data of a vertex in the graph, or fails.
+///
+/// As `map_vertex`, but reports a missing vertex instead of panicking, in
+/// which case the graph is left unchanged.
+pub fn try_map_vertex<
+    Id: Copy + Eq + Hash + Display,
+    Data: Clone + PartialEq,
+    WeightData: Clone + PartialEq,
+    Registry: IdentifierRegistry<Id>,
+    S: BuildHasher + Clone + Default,
+    F: FnOnce(&Data) -> Data,
+>(
+    graph: &mut Graph<Id, Data, WeightData, Registry, S>,
+    vertex_id: Id,
+    map: F,
+) -> Result<(), GraphMutationFailure<Id>> {
+    if !graph.vertices.contains_key(&vertex_id) {
+        return Err(GraphMutationFailure::VertexNotFound(vertex_id));
+    }
+
+    map_vertex(graph, vertex_id, map);
+    Ok(())
+}
+
+/// Maps the data of an edge in the graph, or fails.
+///
+/// As `map_edge`, but reports a missing edge instead of panicking, in which
+/// case the graph is left unchanged.
+pub fn try_map_edge<
+    Id: Copy + Eq + Hash + Display,
+    Data: Clone + PartialEq,
+    WeightData: Clone + PartialEq,
+    Registry: IdentifierRegistry<Id>,
+    S: BuildHasher + Clone + Default,
+    F: FnOnce(&WeightData) -> WeightData,
+>(
+    graph: &mut Graph<Id, Data, WeightData, Registry, S>,
+    edge_id: Id,
+    map: F,
+) -> Result<(), GraphMutationFailure<Id>> {
+    if !graph.edges.contains_key(&edge_id) {
+        return Err(GraphMutationFailure::EdgeNotFound(edge_id));
+    }
+
+    map_edge(graph, edge_id, map);
+    Ok(())
+}
@@ -0,0 +1,149 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+//! Graph Observer module.
+//!
+//! Provides a graph wrapper that notifies registered observers of every
+//! mutation made through it, so that structures derived from the graph can be
+//! kept synchronized without manual invalidation.
+
+use crate::math::graph::*;
+
+/// Graph Events.
+///
+/// Describes a single mutation of an observed graph.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GraphEvent<Id> {
+    /// A vertex with the given id was added.
+    VertexAdded(Id),
+
+    /// An edge with the given id was added between the given vertices.
+    EdgeAdded { edge: Id, from: Id, to: Id },
+
+    /// The data of the vertex with the given id was replaced.
+    VertexMapped(Id),
+
+    /// The data of the edge with the given id was replaced.
+    EdgeMapped(Id),
+}
+
+/// Callback notified of graph events.
+pub type GraphObserver<'o, Id> = Box<dyn FnMut(&GraphEvent<Id>) + 'o>;
+
+/// Observable Graph.
+///
+/// Owns a graph and forwards mutations to it, emitting a `GraphEvent` to each
+/// registered observer, in registration order, after every mutation. Read
+/// access to the underlying graph is unrestricted.
+pub struct ObservableGraph<
+    'o,
+    Id: Copy + Eq + Hash + Display,
+    Data: Clone + PartialEq,
+    WeightData: Clone + PartialEq,
+    Registry: IdentifierRegistry<Id>,
+    S: BuildHasher + Clone + Default = RandomState,
+> {
+    graph: Graph<Id, Data, WeightData, Registry, S>,
+    observers: Vec<GraphObserver<'o, Id>>,
+}
+
+impl<
+        'o,
+        Id: Copy + Eq + Hash + Display,
+        Data: Clone + PartialEq,
+        WeightData: Clone + PartialEq,
+        Registry: IdentifierRegistry<Id>,
+        S: BuildHasher + Clone + Default,
+    > ObservableGraph<'o, Id, Data, WeightData, Registry, S>
+{
+    /// Wraps the given graph, with no observers.
+    pub fn new(graph: Graph<Id, Data, WeightData, Registry, S>) -> Self {
+        ObservableGraph {
+            graph,
+            observers: Vec::new(),
+        }
+    }
+
+    /// Registers an observer that is called with every subsequent event.
+    pub fn subscribe<F: FnMut(&GraphEvent<Id>) + 'o>(&mut self, observer: F) {
+        self.observers.push(Box::new(observer))
+    }
+
+    /// Returns the observed graph.
+    pub fn graph(&self) -> &Graph<Id, Data, WeightData, Registry, S> {
+        &self.graph
+    }
+
+    /// Unwraps the observed graph, dropping all observers.
+    pub fn into_graph(self) -> Graph<Id, Data, WeightData, Registry, S> {
+        self.graph
+    }
+
+    /// Adds a vertex as `mutators::add_vertex` does and emits `VertexAdded`.
+    pub fn add_vertex(&mut self, data: Data) -> Id {
+        let vertex_id = mutators::add_vertex(&mut self.graph, data);
+        self.notify(GraphEvent::VertexAdded(vertex_id));
+        vertex_id
+    }
+
+    /// Adds an edge as `mutators::add_edge` does and emits `EdgeAdded`.
+    pub fn add_edge(&mut self, vertex_from: Id, vertex_to: Id, data: WeightData) -> Id {
+        let edge_count = self.graph.edge_count();
+        let edge_id = mutators::add_edge(&mut self.graph, vertex_from, vertex_to, data);
+        if self.graph.edge_count() == edge_count {
+            // The graph replaced a parallel edge rather than adding one.
+            self.notify(GraphEvent::EdgeMapped(edge_id));
+        } else {
+            self.notify(GraphEvent::EdgeAdded {
+                edge: edge_id,
+                from: vertex_from,
+                to: vertex_to,
+            });
+        }
+        edge_id
+    }
+
+    /// Maps a vertex as `mutators::map_vertex` does and emits `VertexMapped`.
+    pub fn map_vertex<F: FnOnce(&Data) -> Data>(&mut self, vertex_id: Id, map: F) {
+        mutators::map_vertex(&mut self.graph, vertex_id, map);
+        self.notify(GraphEvent::VertexMapped(vertex_id));
+    }
+
+    /// Maps an edge as `mutators::map_edge` does and emits `EdgeMapped`.
+    pub fn map_edge<F: FnOnce(&WeightData) -> WeightData>(&mut self, edge_id: Id, map: F) {
+        mutators::map_edge(&mut self.graph, edge_id, map);
+        self.notify(GraphEvent::EdgeMapped(edge_id));
+    }
+
+    fn notify(&mut self, event: GraphEvent<Id>) {
+        for observer in self.observers.iter_mut() {
+            observer(&event)
+        }
+    }
+}
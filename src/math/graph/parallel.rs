@@ -0,0 +1,125 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+//! Graph Parallel module.
+//!
+//! Provides multi-threaded variants of the graph traversals, available with
+//! the `parallel` feature.
+
+use crate::math::graph::*;
+use rayon::prelude::*;
+
+/// Parallel Graph Visitor trait.
+///
+/// Thread-safe counterpart of `GraphVisitor`. Callbacks take `&self` and may
+/// run concurrently, so implementations keep their state behind atomics or
+/// locks. Vertices at the same depth are visited in no particular order.
+pub trait ParallelGraphVisitor<'a, Id, Data, WeightData>: Sync
+where
+    Id: Copy + Eq + Hash + Display,
+    Data: Clone + PartialEq,
+    WeightData: Clone + PartialEq,
+{
+    fn visit_vertex(&self, vertex: &'a VertexDescriptor<Id, Data>, depth: usize);
+    fn visit_edge(&self, vertex_from: Id, edge: &'a EdgeDescriptor<Id, WeightData>, vertex_to: Id);
+}
+
+/// Parallel Breadth-First Traversal.
+///
+/// Performs a level-synchronous breadth-first traversal: every vertex of the
+/// current frontier is visited, and its out adjacency expanded, in parallel.
+/// Each vertex is visited once, after the edge it was first reached by, and
+/// all vertices of one depth are visited before any of the next. Fails if the
+/// source vertex is not in the graph or the graph is ill-formed.
+pub fn parallel_breadth_first_traversal<
+    'a,
+    Id: Copy + Eq + Hash + Display + Send + Sync + 'a,
+    Data: Clone + PartialEq + Sync + 'a,
+    WeightData: Clone + PartialEq + Sync + 'a,
+    G: GraphStorage<Id, Data, WeightData> + Sync,
+    V: ParallelGraphVisitor<'a, Id, Data, WeightData>,
+>(
+    graph: &'a G,
+    source: Id,
+    visitor: &V,
+) -> Result<(), GraphTraversalFailure<Id>> {
+    if graph.vertex(source).is_none() {
+        return Err(GraphTraversalFailure::SourceNotFound(source));
+    }
+
+    let mut covered_vertices = HashSet::new();
+    let mut frontier = vec![source];
+    let mut depth = 0;
+
+    covered_vertices.insert(source);
+
+    while !frontier.is_empty() {
+        frontier.par_iter().try_for_each(|vertex_id| {
+            let vertex = graph
+                .vertex(*vertex_id)
+                .ok_or(GraphTraversalFailure::VertexNotFound(*vertex_id))?;
+            visitor.visit_vertex(vertex, depth);
+            Ok(())
+        })?;
+
+        let candidates: Vec<(Id, Id, Id)> = frontier
+            .par_iter()
+            .flat_map_iter(|vertex_id| {
+                graph
+                    .out_adjacency(*vertex_id)
+                    .iter()
+                    .map(move |(edge_id, to_vertex_id)| (*vertex_id, *edge_id, *to_vertex_id))
+            })
+            .collect();
+
+        // Claiming vertices is sequential so each is reached by exactly one edge.
+        let transitions: Vec<(Id, Id, Id)> = candidates
+            .into_iter()
+            .filter(|(_, _, to_vertex_id)| covered_vertices.insert(*to_vertex_id))
+            .collect();
+
+        transitions
+            .par_iter()
+            .try_for_each(|(from_vertex_id, edge_id, to_vertex_id)| {
+                let edge = graph
+                    .edge(*edge_id)
+                    .ok_or(GraphTraversalFailure::EdgeNotFound(*edge_id))?;
+                visitor.visit_edge(*from_vertex_id, edge, *to_vertex_id);
+                Ok(())
+            })?;
+
+        frontier = transitions
+            .into_iter()
+            .map(|(_, _, to_vertex_id)| to_vertex_id)
+            .collect();
+        depth += 1;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,231 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+//! Graph Pathfinding module.
+//!
+//! Provides algorithms that find walks between vertices of a graph.
+
+use crate::math::graph::*;
+
+/// Records, for every vertex visited, the vertex and edge it was reached from.
+struct PredecessorRecorder<Id: Copy + Eq + Hash + Display> {
+    predecessors: HashMap<Id, (Id, Id)>,
+}
+
+impl<
+        'a,
+        Id: Copy + Eq + Hash + Display,
+        Data: Clone + PartialEq,
+        WeightData: Clone + PartialEq,
+    > GraphVisitor<'a, Id, Data, WeightData> for PredecessorRecorder<Id>
+{
+    fn reset(&mut self) {
+        self.predecessors = HashMap::new()
+    }
+
+    fn visit_vertex(&mut self, _: &'a VertexDescriptor<Id, Data>) {}
+
+    fn visit_edge(
+        &mut self,
+        vertex_from: Id,
+        edge: &'a EdgeDescriptor<Id, WeightData>,
+        vertex_to: Id,
+    ) {
+        self.predecessors
+            .insert(vertex_to, (vertex_from, *edge.id()));
+    }
+}
+
+/// Finds a walk with the fewest edges from one vertex to another.
+///
+/// Returns `None` if the target vertex is not reachable from the source
+/// vertex. A walk from a vertex to itself contains just that vertex. Fails if
+/// the source vertex is not in the graph or the graph is ill-formed.
+pub fn find_path<
+    'a,
+    Id: Copy + Eq + Hash + Display + 'a,
+    Data: Clone + PartialEq + 'a,
+    WeightData: Clone + PartialEq + 'a,
+    G: GraphStorage<Id, Data, WeightData>,
+>(
+    graph: &'a G,
+    vertex_from: Id,
+    vertex_to: Id,
+) -> Result<Option<Walk<'a, Id, Data, WeightData>>, GraphTraversalFailure<Id>> {
+    let mut recorder = PredecessorRecorder {
+        predecessors: HashMap::new(),
+    };
+    breadth_first_traversal(graph, vertex_from, &mut recorder)?;
+
+    walk_from_predecessors(graph, &recorder.predecessors, vertex_from, vertex_to)
+}
+
+/// Finds a walk with the fewest edges from one vertex to another, as owned
+/// identifiers.
+///
+/// Behaves like `find_path`, but the result does not borrow the graph so it
+/// can be held while the graph is mutated.
+pub fn find_path_ids<
+    Id: Copy + Eq + Hash + Display,
+    Data: Clone + PartialEq,
+    WeightData: Clone + PartialEq,
+    G: GraphStorage<Id, Data, WeightData>,
+>(
+    graph: &G,
+    vertex_from: Id,
+    vertex_to: Id,
+) -> Result<Option<WalkIds<Id>>, GraphTraversalFailure<Id>> {
+    Ok(find_path(graph, vertex_from, vertex_to)?.map(|walk| walk.to_ids()))
+}
+
+/// Finds a walk with the fewest edges from one vertex to another that neither
+/// passes through a blocked vertex nor crosses a blocked edge.
+///
+/// Returns `None` if no such walk exists, including when either end is itself
+/// blocked. Lets a planner route around newly observed obstacles without
+/// modifying the graph. Fails if the source vertex is not in the graph or the
+/// graph is ill-formed.
+pub fn find_path_avoiding<
+    'a,
+    Id: Copy + Eq + Hash + Display + 'a,
+    Data: Clone + PartialEq + 'a,
+    WeightData: Clone + PartialEq + 'a,
+    G: GraphStorage<Id, Data, WeightData>,
+>(
+    graph: &'a G,
+    vertex_from: Id,
+    vertex_to: Id,
+    blocked_vertices: &HashSet<Id>,
+    blocked_edges: &HashSet<Id>,
+) -> Result<Option<Walk<'a, Id, Data, WeightData>>, GraphTraversalFailure<Id>> {
+    if graph.vertex(vertex_from).is_none() {
+        return Err(GraphTraversalFailure::SourceNotFound(vertex_from));
+    }
+
+    if blocked_vertices.contains(&vertex_from) || blocked_vertices.contains(&vertex_to) {
+        return Ok(None);
+    }
+
+    let mut predecessors = HashMap::new();
+    let mut frontier = VecDeque::from([vertex_from]);
+
+    while let Some(vertex_id) = frontier.pop_front() {
+        if vertex_id == vertex_to {
+            break;
+        }
+
+        for (edge_id, to_vertex_id) in graph.out_adjacency(vertex_id) {
+            if *to_vertex_id == vertex_from
+                || predecessors.contains_key(to_vertex_id)
+                || blocked_vertices.contains(to_vertex_id)
+                || blocked_edges.contains(edge_id)
+            {
+                continue;
+            }
+
+            predecessors.insert(*to_vertex_id, (vertex_id, *edge_id));
+            frontier.push_back(*to_vertex_id);
+        }
+    }
+
+    walk_from_predecessors(graph, &predecessors, vertex_from, vertex_to)
+}
+
+/// Finds a walk with the fewest edges from one vertex to another that avoids
+/// the blocked vertices and edges, as owned identifiers.
+///
+/// Behaves like `find_path_avoiding`, but the result does not borrow the graph
+/// so it can be held while the graph is mutated.
+pub fn find_path_avoiding_ids<
+    Id: Copy + Eq + Hash + Display,
+    Data: Clone + PartialEq,
+    WeightData: Clone + PartialEq,
+    G: GraphStorage<Id, Data, WeightData>,
+>(
+    graph: &G,
+    vertex_from: Id,
+    vertex_to: Id,
+    blocked_vertices: &HashSet<Id>,
+    blocked_edges: &HashSet<Id>,
+) -> Result<Option<WalkIds<Id>>, GraphTraversalFailure<Id>> {
+    Ok(find_path_avoiding(
+        graph,
+        vertex_from,
+        vertex_to,
+        blocked_vertices,
+        blocked_edges,
+    )?
+    .map(|walk| walk.to_ids()))
+}
+
+/// Rebuilds the walk to a vertex by following the (vertex, edge) each vertex
+/// was reached from back to the source.
+///
+/// Fails if a recorded vertex or edge is not in the graph.
+fn walk_from_predecessors<
+    'a,
+    Id: Copy + Eq + Hash + Display + 'a,
+    Data: Clone + PartialEq + 'a,
+    WeightData: Clone + PartialEq + 'a,
+    G: GraphStorage<Id, Data, WeightData>,
+>(
+    graph: &'a G,
+    predecessors: &HashMap<Id, (Id, Id)>,
+    vertex_from: Id,
+    vertex_to: Id,
+) -> Result<Option<Walk<'a, Id, Data, WeightData>>, GraphTraversalFailure<Id>> {
+    if vertex_to != vertex_from && !predecessors.contains_key(&vertex_to) {
+        return Ok(None);
+    }
+
+    let vertex = |id: Id| {
+        graph.vertex(id).ok_or(GraphTraversalFailure::IllFormed(
+            GraphViolation::UnknownVertex(id),
+        ))
+    };
+    let edge = |id: Id| {
+        graph.edge(id).ok_or(GraphTraversalFailure::IllFormed(
+            GraphViolation::UnknownEdge(id),
+        ))
+    };
+
+    let mut vertices = LinkedList::new();
+    let mut edges = LinkedList::new();
+    let mut current = vertex_to;
+
+    vertices.push_front(vertex(current)?);
+    while current != vertex_from {
+        let (previous, edge_id) = predecessors[&current];
+        edges.push_front(edge(edge_id)?);
+        vertices.push_front(vertex(previous)?);
+        current = previous;
+    }
+
+    Ok(Some(Walk { vertices, edges }))
+}
@@ -31,7 +31,10 @@ SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 mod tests {
     use std::marker::PhantomData;
 
-    use crate::{math::graph::*, utility::idregistry::ExplicitIntegralIdentifierRegistry};
+    use crate::{
+        math::graph::*,
+        utility::idregistry::{ExplicitIntegralIdentifierRegistry, IdentifierRegistryFailure},
+    };
 
     struct CountingGraphVisitor {
         vertex_count: usize,
@@ -47,7 +50,6 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "The breadth-first search must begin on a vertex in the graph.")]
     fn graph_empty_visiting() {
         let g: Graph<usize, f32, f32, _> = Graph::new(
             ExplicitIntegralIdentifierRegistry::null_registry(),
@@ -58,7 +60,14 @@ mod tests {
             edge_count: 0,
         };
 
-        breadth_first_traversal(&g, 0, &mut visitor);
+        assert_eq!(
+            breadth_first_traversal(&g, 0, &mut visitor),
+            Err(GraphTraversalFailure::SourceNotFound(0))
+        );
+        assert_eq!(
+            best_first_traversal(&g, 0, |_, _, _| 0.0, &mut visitor),
+            Err(GraphTraversalFailure::SourceNotFound(0))
+        );
     }
 
     #[test]
@@ -78,17 +87,17 @@ mod tests {
             edge_count: 0,
         };
 
-        breadth_first_traversal(&g, v1, &mut visitor);
+        breadth_first_traversal(&g, v1, &mut visitor).unwrap();
 
         assert_eq!(visitor.edge_count, 0);
         assert_eq!(visitor.vertex_count, 1);
 
-        breadth_first_traversal(&g, v2, &mut visitor);
+        breadth_first_traversal(&g, v2, &mut visitor).unwrap();
 
         assert_eq!(visitor.edge_count, 1);
         assert_eq!(visitor.vertex_count, 2);
 
-        breadth_first_traversal(&g, v3, &mut visitor);
+        breadth_first_traversal(&g, v3, &mut visitor).unwrap();
 
         assert_eq!(visitor.edge_count, 0);
         assert_eq!(visitor.vertex_count, 1);
@@ -134,60 +143,1412 @@ mod tests {
         // BFS from V1 should result in the entire vertex set.
         {
             let mut vertex_collector = VertexCollector::new(|_| true);
-            breadth_first_traversal(&g, v1, &mut vertex_collector);
+            breadth_first_traversal(&g, v1, &mut vertex_collector).unwrap();
             let g_bfs: LinkedList<usize> = vertex_collector
                 .vertices()
                 .iter()
-                .map(|vdesc| vdesc.id().clone())
+                .map(|vdesc| *vdesc.id())
                 .collect();
             assert_eq!(g_bfs, LinkedList::from([v1, v2, v3, v4, v5]))
         }
         {
             // BFS from V2 and V5 are just the two element set containing V2 and V5.
             let mut vertex_collector = VertexCollector::new(|_| true);
-            breadth_first_traversal(&g, v2, &mut vertex_collector);
+            breadth_first_traversal(&g, v2, &mut vertex_collector).unwrap();
             let g_bfs: LinkedList<usize> = vertex_collector
                 .vertices()
                 .iter()
-                .map(|vdesc| vdesc.id().clone())
+                .map(|vdesc| *vdesc.id())
                 .collect();
             assert_eq!(g_bfs, LinkedList::from([v2, v5]));
         }
         {
             // BFS from V2 and V5 are just the two element set containing V2 and V5.
             let mut vertex_collector = VertexCollector::new(|_| true);
-            breadth_first_traversal(&g, v5, &mut vertex_collector);
+            breadth_first_traversal(&g, v5, &mut vertex_collector).unwrap();
             let g_bfs: LinkedList<usize> = vertex_collector
                 .vertices()
                 .iter()
-                .map(|vdesc| vdesc.id().clone())
+                .map(|vdesc| *vdesc.id())
                 .collect();
             assert_eq!(g_bfs, LinkedList::from([v5, v2]));
         }
         {
             // BFS from V3 is the entire set.
             let mut vertex_collector = VertexCollector::new(|_| true);
-            breadth_first_traversal(&g, v3, &mut vertex_collector);
+            breadth_first_traversal(&g, v3, &mut vertex_collector).unwrap();
             let g_bfs: LinkedList<usize> = vertex_collector
                 .vertices()
                 .iter()
-                .map(|vdesc| vdesc.id().clone())
+                .map(|vdesc| *vdesc.id())
                 .collect();
             assert_eq!(g_bfs, LinkedList::from([v3, v2, v5, v4, v1]))
         }
         {
             // BFS from V4 is the entire set.
             let mut vertex_collector = VertexCollector::new(|_| true);
-            breadth_first_traversal(&g, v4, &mut vertex_collector);
+            breadth_first_traversal(&g, v4, &mut vertex_collector).unwrap();
             let g_bfs: LinkedList<usize> = vertex_collector
                 .vertices()
                 .iter()
-                .map(|vdesc| vdesc.id().clone())
+                .map(|vdesc| *vdesc.id())
                 .collect();
             assert_eq!(g_bfs, LinkedList::from([v4, v5, v1, v2, v3]))
         }
     }
 
+    #[test]
+    fn graph_uniform_cost_best_first() {
+        let mut g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(5),
+            ExplicitIntegralIdentifierRegistry::new(6),
+        );
+
+        let v1 = mutators::add_vertex(&mut g, 0.0);
+        let v2 = mutators::add_vertex(&mut g, 0.0);
+        let v3 = mutators::add_vertex(&mut g, 0.0);
+        let v4 = mutators::add_vertex(&mut g, 0.0);
+
+        mutators::add_edge(&mut g, v1, v2, 4.0);
+        mutators::add_edge(&mut g, v1, v3, 1.0);
+        mutators::add_edge(&mut g, v3, v2, 1.0);
+        mutators::add_edge(&mut g, v2, v4, 1.0);
+        mutators::add_edge(&mut g, v3, v4, 5.0);
+
+        let mut vertex_collector = VertexCollector::new(|_| true);
+        best_first_traversal(
+            &g,
+            v1,
+            |cost: Option<&f32>, edge: Option<&EdgeDescriptor<usize, f32>>, _| {
+                cost.copied().unwrap_or(0.0) + edge.map(|e| *e.data()).unwrap_or(0.0)
+            },
+            &mut vertex_collector,
+        )
+        .unwrap();
+        let g_ucs: LinkedList<usize> = vertex_collector
+            .vertices()
+            .iter()
+            .map(|vdesc| *vdesc.id())
+            .collect();
+        assert_eq!(g_ucs, LinkedList::from([v1, v3, v2, v4]));
+
+        // Each vertex is reached through its cheapest edge only.
+        let mut visitor = CountingGraphVisitor {
+            vertex_count: 0,
+            edge_count: 0,
+        };
+        best_first_traversal(
+            &g,
+            v1,
+            |cost: Option<&f32>, edge: Option<&EdgeDescriptor<usize, f32>>, _| {
+                cost.copied().unwrap_or(0.0) + edge.map(|e| *e.data()).unwrap_or(0.0)
+            },
+            &mut visitor,
+        )
+        .unwrap();
+        assert_eq!(visitor.vertex_count, 4);
+        assert_eq!(visitor.edge_count, 3);
+    }
+
+    #[test]
+    fn graph_greedy_best_first() {
+        let mut g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(5),
+            ExplicitIntegralIdentifierRegistry::new(6),
+        );
+
+        let v1 = mutators::add_vertex(&mut g, 3.0);
+        let v2 = mutators::add_vertex(&mut g, 2.0);
+        let v3 = mutators::add_vertex(&mut g, 1.0);
+        let v4 = mutators::add_vertex(&mut g, 0.0);
+
+        mutators::add_edge(&mut g, v1, v2, 1.0);
+        mutators::add_edge(&mut g, v1, v3, 1.0);
+        mutators::add_edge(&mut g, v2, v4, 1.0);
+
+        // The heuristic stored in the vertex data prefers v3 over v2, even
+        // though both were discovered together.
+        let mut vertex_collector = VertexCollector::new(|_| true);
+        best_first_traversal(
+            &g,
+            v1,
+            |_: Option<&f32>, _, vertex| *vertex.data(),
+            &mut vertex_collector,
+        )
+        .unwrap();
+        let g_greedy: LinkedList<usize> = vertex_collector
+            .vertices()
+            .iter()
+            .map(|vdesc| *vdesc.id())
+            .collect();
+        assert_eq!(g_greedy, LinkedList::from([v1, v3, v2, v4]));
+    }
+
+    #[test]
+    fn graph_best_first_rejects_nan_priorities() {
+        let mut g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(3),
+            ExplicitIntegralIdentifierRegistry::new(2),
+        );
+        let v1 = mutators::add_vertex(&mut g, 0.0);
+        let v2 = mutators::add_vertex(&mut g, f32::NAN);
+        let v3 = mutators::add_vertex(&mut g, 1.0);
+        mutators::add_edge(&mut g, v1, v2, 1.0);
+        mutators::add_edge(&mut g, v1, v3, 1.0);
+
+        let mut vertex_collector = VertexCollector::new(|_| true);
+        assert_eq!(
+            best_first_traversal(
+                &g,
+                v1,
+                |_: Option<&f32>, _, vertex| *vertex.data(),
+                &mut vertex_collector,
+            ),
+            Err(GraphTraversalFailure::IncomparablePriority(v2))
+        );
+    }
+
+    #[test]
+    fn graph_depth_limited_traversal() {
+        let mut g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(4),
+            ExplicitIntegralIdentifierRegistry::new(4),
+        );
+
+        let v1 = mutators::add_vertex(&mut g, 0.0);
+        let v2 = mutators::add_vertex(&mut g, 0.0);
+        let v3 = mutators::add_vertex(&mut g, 0.0);
+        let v4 = mutators::add_vertex(&mut g, 0.0);
+
+        mutators::add_edge(&mut g, v1, v2, 1.0);
+        mutators::add_edge(&mut g, v2, v3, 1.0);
+        mutators::add_edge(&mut g, v3, v4, 1.0);
+        mutators::add_edge(&mut g, v4, v1, 1.0);
+
+        let mut visitor = CountingGraphVisitor {
+            vertex_count: 0,
+            edge_count: 0,
+        };
+
+        assert!(depth_limited_traversal(&g, v1, 0, &mut visitor).unwrap());
+        assert_eq!(visitor.vertex_count, 1);
+        assert_eq!(visitor.edge_count, 0);
+
+        assert!(depth_limited_traversal(&g, v1, 2, &mut visitor).unwrap());
+        assert_eq!(visitor.vertex_count, 3);
+        assert_eq!(visitor.edge_count, 2);
+
+        // The cycle back to v1 is not a truncation, since v1 was visited.
+        assert!(!depth_limited_traversal(&g, v1, 3, &mut visitor).unwrap());
+        assert_eq!(visitor.vertex_count, 4);
+        assert_eq!(visitor.edge_count, 3);
+    }
+
+    #[test]
+    fn graph_iterative_deepening_traversal() {
+        let mut g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(4),
+            ExplicitIntegralIdentifierRegistry::new(3),
+        );
+
+        let v1 = mutators::add_vertex(&mut g, 0.0);
+        let v2 = mutators::add_vertex(&mut g, 0.0);
+        let v3 = mutators::add_vertex(&mut g, 0.0);
+        let v4 = mutators::add_vertex(&mut g, 1.0);
+
+        mutators::add_edge(&mut g, v1, v2, 1.0);
+        mutators::add_edge(&mut g, v2, v3, 1.0);
+        mutators::add_edge(&mut g, v3, v4, 1.0);
+
+        let mut goal_collector = VertexCollector::new(|data: &f32| *data == 1.0);
+        let depth = iterative_deepening_traversal(&g, v1, 10, &mut goal_collector, |c| {
+            !c.vertices().is_empty()
+        })
+        .unwrap();
+        assert_eq!(depth, Some(3));
+
+        let depth = iterative_deepening_traversal(&g, v1, 2, &mut goal_collector, |c| {
+            !c.vertices().is_empty()
+        })
+        .unwrap();
+        assert_eq!(depth, None);
+
+        // Stops early once the reachable set is exhausted.
+        let mut passes = 0;
+        let depth = iterative_deepening_traversal(&g, v3, 10, &mut goal_collector, |_| {
+            passes += 1;
+            false
+        })
+        .unwrap();
+        assert_eq!(depth, None);
+        assert_eq!(passes, 2);
+    }
+
+    #[test]
+    fn graph_find_path_walk() {
+        let mut g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(4),
+            ExplicitIntegralIdentifierRegistry::new(4),
+        );
+
+        let v1 = mutators::add_vertex(&mut g, 0.0);
+        let v2 = mutators::add_vertex(&mut g, 0.0);
+        let v3 = mutators::add_vertex(&mut g, 0.0);
+        let v4 = mutators::add_vertex(&mut g, 0.0);
+
+        let e12 = mutators::add_edge(&mut g, v1, v2, 1.5);
+        let e23 = mutators::add_edge(&mut g, v2, v3, 2.0);
+        mutators::add_edge(&mut g, v3, v1, 4.0);
+
+        let walk = pathfinding::find_path(&g, v1, v3)
+            .unwrap()
+            .expect("Expected a walk from v1 to v3.");
+        assert_eq!(walk.start(), v1);
+        assert_eq!(walk.end(), v3);
+        assert_eq!(walk.hop_count(), 2);
+        assert_eq!(
+            walk.vertices().map(|v| *v.id()).collect::<Vec<_>>(),
+            vec![v1, v2, v3]
+        );
+        assert_eq!(
+            walk.edges().map(|e| *e.id()).collect::<Vec<_>>(),
+            vec![e12, e23]
+        );
+        assert_eq!(walk.total_cost(0.0, |cost, weight| cost + weight), 3.5);
+
+        let trivial = pathfinding::find_path(&g, v2, v2)
+            .unwrap()
+            .expect("Expected a trivial walk.");
+        assert_eq!(trivial.start(), v2);
+        assert_eq!(trivial.end(), v2);
+        assert_eq!(trivial.hop_count(), 0);
+
+        assert!(pathfinding::find_path(&g, v1, v4).unwrap().is_none());
+
+        assert_eq!(
+            pathfinding::find_path_ids(&g, v1, v3).unwrap(),
+            Some(WalkIds {
+                vertices: vec![v1, v2, v3],
+                edges: vec![e12, e23]
+            })
+        );
+        assert_eq!(pathfinding::find_path_ids(&g, v1, v4).unwrap(), None);
+    }
+
+    #[test]
+    fn graph_walk_concat_and_validity() {
+        let mut g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(4),
+            ExplicitIntegralIdentifierRegistry::new(4),
+        );
+
+        let v1 = mutators::add_vertex(&mut g, 0.0);
+        let v2 = mutators::add_vertex(&mut g, 0.0);
+        let v3 = mutators::add_vertex(&mut g, 0.0);
+        let v4 = mutators::add_vertex(&mut g, 0.0);
+
+        mutators::add_edge(&mut g, v1, v2, 1.0);
+        mutators::add_edge(&mut g, v2, v3, 1.0);
+        mutators::add_edge(&mut g, v3, v4, 1.0);
+
+        let first = pathfinding::find_path(&g, v1, v2).unwrap().unwrap();
+        let second = pathfinding::find_path(&g, v2, v4).unwrap().unwrap();
+        assert!(first.is_valid_in(&g));
+        assert!(second.is_valid_in(&g));
+
+        let joined = first
+            .concat(second)
+            .expect("Expected walks sharing v2 to join.");
+        assert_eq!(
+            joined.vertices().map(|v| *v.id()).collect::<Vec<_>>(),
+            vec![v1, v2, v3, v4]
+        );
+        assert_eq!(joined.hop_count(), 3);
+        assert!(joined.is_valid_in(&g));
+
+        let first = pathfinding::find_path(&g, v1, v2).unwrap().unwrap();
+        let disjoint = pathfinding::find_path(&g, v3, v4).unwrap().unwrap();
+        assert!(first.concat(disjoint).is_none());
+
+        // A walk is not valid in a graph where its edges go elsewhere.
+        let mut h: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(4),
+            ExplicitIntegralIdentifierRegistry::new(4),
+        );
+        for _ in 0..4 {
+            mutators::add_vertex(&mut h, 0.0);
+        }
+        mutators::add_edge(&mut h, v2, v1, 1.0);
+        mutators::add_edge(&mut h, v2, v3, 1.0);
+
+        let walk = pathfinding::find_path(&g, v1, v3).unwrap().unwrap();
+        assert!(!walk.is_valid_in(&h));
+    }
+
+    #[test]
+    fn graph_neighbour_iterators() {
+        let mut g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(3),
+            ExplicitIntegralIdentifierRegistry::new(3),
+        );
+
+        let v1 = mutators::add_vertex(&mut g, 0.0);
+        let v2 = mutators::add_vertex(&mut g, 0.0);
+        let v3 = mutators::add_vertex(&mut g, 0.0);
+
+        let e12 = mutators::add_edge(&mut g, v1, v2, 1.0);
+        let e13 = mutators::add_edge(&mut g, v1, v3, 2.0);
+        let e32 = mutators::add_edge(&mut g, v3, v2, 3.0);
+
+        let out_v1: Vec<(usize, usize)> = g
+            .out_neighbours(v1)
+            .map(|(e, v)| (*e.id(), *v.id()))
+            .collect();
+        assert_eq!(out_v1, vec![(e12, v2), (e13, v3)]);
+
+        let in_v2: Vec<(usize, usize)> = g
+            .in_neighbours(v2)
+            .map(|(e, v)| (*e.id(), *v.id()))
+            .collect();
+        assert_eq!(in_v2, vec![(e12, v1), (e32, v3)]);
+
+        assert_eq!(g.out_neighbours(v2).count(), 0);
+        assert_eq!(g.out_neighbours_of(v1).len(), 2);
+        assert_eq!(g.in_neighbours_of(v2).len(), 2);
+        assert!(g.is_adjacent(v3, v2));
+        assert!(!g.is_adjacent(v2, v3));
+    }
+
+    #[test]
+    fn graph_enumeration() {
+        let mut g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(3),
+            ExplicitIntegralIdentifierRegistry::new(3),
+        );
+        assert_eq!(g.vertex_count(), 0);
+        assert_eq!(g.edge_count(), 0);
+
+        let v1 = mutators::add_vertex(&mut g, 1.0);
+        let v2 = mutators::add_vertex(&mut g, 2.0);
+        let v3 = mutators::add_vertex(&mut g, 3.0);
+
+        let e12 = mutators::add_edge(&mut g, v1, v2, 0.5);
+        let e23 = mutators::add_edge(&mut g, v2, v3, 1.5);
+
+        assert_eq!(g.vertex_count(), 3);
+        assert_eq!(g.edge_count(), 2);
+
+        let mut vertex_ids: Vec<usize> = g.vertices().map(|v| *v.id()).collect();
+        vertex_ids.sort();
+        assert_eq!(vertex_ids, vec![v1, v2, v3]);
+        assert_eq!(g.vertices().map(|v| *v.data()).sum::<f32>(), 6.0);
+
+        let mut edge_ids: Vec<usize> = g.edges().map(|e| *e.id()).collect();
+        edge_ids.sort();
+        assert_eq!(edge_ids, vec![e12, e23]);
+
+        assert_eq!(g.edge_endpoints(e12), Some((v1, v2)));
+        assert_eq!(g.edge_endpoints(e23), Some((v2, v3)));
+        assert_eq!(g.edge_endpoints(42), None);
+
+        let reversed = g.reverse_graph();
+        assert_eq!(reversed.edge_endpoints(e12), Some((v2, v1)));
+    }
+
+    #[test]
+    fn graph_transaction_commits() {
+        let mut g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(2),
+            ExplicitIntegralIdentifierRegistry::new(2),
+        );
+        let v1 = mutators::add_vertex(&mut g, 1.0);
+        let v2 = mutators::add_vertex(&mut g, 2.0);
+
+        let mut add_vertex = mutators::GraphVertexAdditionMutator::new(3.0);
+        let mut add_edge = mutators::GraphEdgeAdditionMutator::new(v1, 0.5, v2);
+
+        mutators::GraphTransaction::new()
+            .with(&mut add_vertex)
+            .with(&mut add_edge)
+            .apply(&mut g)
+            .expect("Expected the transaction to succeed.");
+
+        let v3 = add_vertex.vertex_id().expect("Expected a new vertex.");
+        let e12 = add_edge.edge_id().expect("Expected a new edge.");
+        assert_eq!(g.vertex_count(), 3);
+        assert_eq!(g.edge_count(), 1);
+        assert_eq!(g.edge_endpoints(e12), Some((v1, v2)));
+        assert!(g.vertices().any(|v| *v.id() == v3));
+    }
+
+    #[test]
+    fn graph_transaction_rolls_back() {
+        let mut g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(2),
+            ExplicitIntegralIdentifierRegistry::new(2),
+        );
+        let v1 = mutators::add_vertex(&mut g, 1.0);
+        let v2 = mutators::add_vertex(&mut g, 2.0);
+
+        let mut add_vertex = mutators::GraphVertexAdditionMutator::new(3.0);
+        let mut add_edge = mutators::GraphEdgeAdditionMutator::new(v1, 0.5, v2);
+        let mut add_bad_edge = mutators::GraphEdgeAdditionMutator::new(v1, 0.5, 42);
+
+        let result = mutators::GraphTransaction::new()
+            .with(&mut add_vertex)
+            .with(&mut add_edge)
+            .with(&mut add_bad_edge)
+            .apply(&mut g);
+
+        assert_eq!(result, Err(GraphMutationFailure::VertexNotFound(42)));
+        assert_eq!(g.vertex_count(), 2);
+        assert_eq!(g.edge_count(), 0);
+        assert!(!g.is_adjacent(v1, v2));
+
+        assert!(g.validate().is_ok());
+
+        // Mutators no longer report identifiers from the discarded graph.
+        assert_eq!(add_vertex.vertex_id(), None);
+        assert_eq!(add_edge.edge_id(), None);
+
+        // Undone mutators get their data back and can be applied again.
+        mutators::GraphTransaction::new()
+            .with(&mut add_vertex)
+            .with(&mut add_edge)
+            .apply(&mut g)
+            .expect("Expected the retried transaction to succeed.");
+        assert_eq!(g.vertex_count(), 3);
+        assert_eq!(
+            g.edge_endpoints(add_edge.edge_id().unwrap()),
+            Some((v1, v2))
+        );
+    }
+
+    #[test]
+    fn graph_mapping_mutators() {
+        let mut g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(2),
+            ExplicitIntegralIdentifierRegistry::new(1),
+        );
+        let v1 = mutators::add_vertex(&mut g, 1.0);
+        let v2 = mutators::add_vertex(&mut g, 2.0);
+        let e12 = mutators::add_edge(&mut g, v1, v2, 0.5);
+
+        mutators::map_vertex(&mut g, v2, |data| data * 10.0);
+        mutators::map_edge(&mut g, e12, |data| data + 1.0);
+
+        assert_eq!(g.select_vertices_with_data(20.0).len(), 1);
+        assert_eq!(*g.out_neighbours(v1).next().unwrap().0.data(), 1.5);
+
+        let mut mapper = mutators::GraphVertexMappingMutator::new(7, |data: &f32| *data);
+        let (g, failure) = mapper.try_mutate(g).err().unwrap();
+        assert_eq!(failure, GraphMutationFailure::VertexNotFound(7));
+        assert_eq!(g.vertex_count(), 2);
+
+        let mut mapper = mutators::GraphEdgeMappingMutator::new(e12, |data: &f32| data * 4.0);
+        let g = mapper.try_mutate(g).ok().unwrap();
+        assert_eq!(*g.out_neighbours(v1).next().unwrap().0.data(), 6.0);
+        let g = mapper.undo(g);
+        assert_eq!(*g.out_neighbours(v1).next().unwrap().0.data(), 1.5);
+    }
+
+    #[test]
+    fn graph_observer_events() {
+        use std::cell::RefCell;
+
+        let events = RefCell::new(Vec::new());
+        let mut g: observer::ObservableGraph<usize, f32, f32, _> =
+            observer::ObservableGraph::new(Graph::new(
+                ExplicitIntegralIdentifierRegistry::new(2),
+                ExplicitIntegralIdentifierRegistry::new(1),
+            ));
+        g.subscribe(|event| events.borrow_mut().push(*event));
+
+        let v1 = g.add_vertex(1.0);
+        let v2 = g.add_vertex(2.0);
+        let e12 = g.add_edge(v1, v2, 0.5);
+        g.map_vertex(v1, |data| data + 1.0);
+        g.map_edge(e12, |data| data * 2.0);
+
+        assert_eq!(g.graph().vertex_count(), 2);
+        drop(g);
+        assert_eq!(
+            events.into_inner(),
+            vec![
+                observer::GraphEvent::VertexAdded(v1),
+                observer::GraphEvent::VertexAdded(v2),
+                observer::GraphEvent::EdgeAdded {
+                    edge: e12,
+                    from: v1,
+                    to: v2
+                },
+                observer::GraphEvent::VertexMapped(v1),
+                observer::GraphEvent::EdgeMapped(e12),
+            ]
+        );
+    }
+
+    #[test]
+    fn graph_mutator_pipeline() {
+        // Re-usable mutator doubling the weight of every edge.
+        struct DoubleWeights;
+
+        impl GraphMutator<usize, f32, f32, ExplicitIntegralIdentifierRegistry> for DoubleWeights {
+            fn mutate(
+                &mut self,
+                graph: Graph<usize, f32, f32, ExplicitIntegralIdentifierRegistry>,
+            ) -> Graph<usize, f32, f32, ExplicitIntegralIdentifierRegistry> {
+                let mut graph = graph;
+                let edge_ids: Vec<usize> = graph.edges().map(|e| *e.id()).collect();
+                for edge_id in edge_ids {
+                    mutators::map_edge(&mut graph, edge_id, |w| w * 2.0);
+                }
+                graph
+            }
+
+            fn undo(
+                &mut self,
+                graph: Graph<usize, f32, f32, ExplicitIntegralIdentifierRegistry>,
+            ) -> Graph<usize, f32, f32, ExplicitIntegralIdentifierRegistry> {
+                let mut graph = graph;
+                let edge_ids: Vec<usize> = graph.edges().map(|e| *e.id()).collect();
+                for edge_id in edge_ids {
+                    mutators::map_edge(&mut graph, edge_id, |w| w / 2.0);
+                }
+                graph
+            }
+        }
+
+        let mut g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(2),
+            ExplicitIntegralIdentifierRegistry::new(2),
+        );
+        let v1 = mutators::add_vertex(&mut g, 1.0);
+        let v2 = mutators::add_vertex(&mut g, 2.0);
+
+        let mut pipeline = mutators::MutatorPipeline::new()
+            .with(mutators::GraphEdgeAdditionMutator::new(v1, 1.0, v2))
+            .with(mutators::GraphVertexAdditionMutator::new(3.0))
+            .with(DoubleWeights.repeat(3))
+            .with(mutators::GraphVertexMappingMutator::new(v1, |d: &f32| {
+                d + 1.0
+            }));
+        g = pipeline.mutate(g);
+
+        assert_eq!(g.vertex_count(), 3);
+        assert_eq!(*g.out_neighbours(v1).next().unwrap().0.data(), 8.0);
+        assert_eq!(g.select_vertices_with_data(2.0).len(), 2);
+
+        let mut chained = DoubleWeights.then(mutators::GraphEdgeAdditionMutator::new(v2, 1.0, 42));
+        let (g, failure) = chained.try_mutate(g).err().unwrap();
+        assert_eq!(failure, GraphMutationFailure::VertexNotFound(42));
+        // The doubling applied before the failure was undone.
+        assert_eq!(*g.out_neighbours(v1).next().unwrap().0.data(), 8.0);
+
+        let g = pipeline.undo(g);
+        assert_eq!(g.vertex_count(), 2);
+        assert_eq!(g.edge_count(), 0);
+        assert_eq!(g.select_vertices_with_data(1.0).len(), 1);
+        assert!(g.validate().is_ok());
+    }
+
+    #[test]
+    fn graph_reversed_view() {
+        let mut g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(3),
+            ExplicitIntegralIdentifierRegistry::new(2),
+        );
+        let v1 = mutators::add_vertex(&mut g, 0.0);
+        let v2 = mutators::add_vertex(&mut g, 0.0);
+        let v3 = mutators::add_vertex(&mut g, 0.0);
+        mutators::add_edge(&mut g, v1, v2, 1.0);
+        mutators::add_edge(&mut g, v2, v3, 1.0);
+
+        {
+            let reversed = g.reversed();
+            let mut vertex_collector = VertexCollector::new(|_| true);
+            breadth_first_traversal(&reversed, v3, &mut vertex_collector).unwrap();
+            let g_bfs: LinkedList<usize> = vertex_collector
+                .vertices()
+                .iter()
+                .map(|vdesc| *vdesc.id())
+                .collect();
+            assert_eq!(g_bfs, LinkedList::from([v3, v2, v1]));
+
+            let walk = pathfinding::find_path(&reversed, v3, v1).unwrap().unwrap();
+            assert!(walk.is_valid_in(&reversed));
+            assert!(!walk.is_valid_in(&g));
+
+            // Reversing twice yields the original adjacency.
+            let twice = Reversed::new(&reversed);
+            assert!(pathfinding::find_path(&twice, v1, v3).unwrap().is_some());
+        }
+
+        // The original graph is untouched and still usable.
+        mutators::add_edge(&mut g, v3, v1, 1.0);
+        assert!(g.is_adjacent(v1, v2));
+        assert!(g.is_adjacent(v3, v1));
+    }
+
+    #[test]
+    fn graph_csr_backend() {
+        let mut g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(5),
+            ExplicitIntegralIdentifierRegistry::new(6),
+        );
+        let v1 = mutators::add_vertex(&mut g, 3.0);
+        let v2 = mutators::add_vertex(&mut g, 2.0);
+        let v3 = mutators::add_vertex(&mut g, 1.0);
+        let v4 = mutators::add_vertex(&mut g, 0.0);
+        let v5 = mutators::add_vertex(&mut g, 5.0);
+        mutators::add_edge(&mut g, v1, v2, 4.0);
+        mutators::add_edge(&mut g, v1, v3, 1.0);
+        mutators::add_edge(&mut g, v3, v2, 1.0);
+        mutators::add_edge(&mut g, v2, v4, 1.0);
+        mutators::add_edge(&mut g, v3, v4, 5.0);
+
+        let csr = csr::CsrGraph::from_graph(&g).unwrap();
+        assert_eq!(csr.vertex_count(), 5);
+        assert_eq!(csr.edge_count(), 5);
+
+        for vertex in g.vertices() {
+            let id = *vertex.id();
+            assert_eq!(csr.out_adjacency(id), g.out_adjacency(id));
+            assert_eq!(csr.in_adjacency(id), g.in_adjacency(id));
+        }
+        assert!(csr.out_adjacency(v5).is_empty());
+        assert!(csr.out_adjacency(42).is_empty());
+
+        // Algorithms run unchanged on either backend.
+        let all = |_: &f32| true;
+        let collect = |collector: &VertexCollector<usize, f32, _>| -> Vec<usize> {
+            collector.vertices().iter().map(|v| *v.id()).collect()
+        };
+        let mut on_graph = VertexCollector::new(all);
+        let mut on_csr = VertexCollector::new(all);
+        breadth_first_traversal(&g, v1, &mut on_graph).unwrap();
+        breadth_first_traversal(&csr, v1, &mut on_csr).unwrap();
+        assert_eq!(collect(&on_graph), collect(&on_csr));
+
+        // The CSR's own traversals work on dense indices but visit in the
+        // same order.
+        let mut on_indices = VertexCollector::new(all);
+        csr.breadth_first_traversal(v1, &mut on_indices).unwrap();
+        assert_eq!(collect(&on_graph), collect(&on_indices));
+        assert!(csr.depth_limited_traversal(v1, 1, &mut on_indices).unwrap());
+        assert_eq!(collect(&on_indices), vec![v1, v2, v3]);
+        assert_eq!(
+            csr.breadth_first_traversal(42, &mut on_indices),
+            Err(GraphTraversalFailure::SourceNotFound(42))
+        );
+
+        // Storage order follows identifiers, not the source graph's hasher.
+        let ids: Vec<usize> = csr.vertices().map(|v| *v.id()).collect();
+        assert_eq!(ids, vec![v1, v2, v3, v4, v5]);
+
+        let uniform_cost = |cost: Option<&f32>, edge: Option<&EdgeDescriptor<usize, f32>>, _| {
+            cost.copied().unwrap_or(0.0) + edge.map(|e| *e.data()).unwrap_or(0.0)
+        };
+        let mut on_csr = VertexCollector::new(all);
+        best_first_traversal(&csr, v1, uniform_cost, &mut on_csr).unwrap();
+        assert_eq!(collect(&on_csr), vec![v1, v3, v2, v4]);
+
+        let reversed = Reversed::new(&csr);
+        let walk = pathfinding::find_path(&reversed, v4, v1).unwrap().unwrap();
+        assert!(walk.is_valid_in(&reversed));
+    }
+
+    #[test]
+    fn graph_deterministic_hasher_iteration_order() {
+        let build = || {
+            let mut g: Graph<usize, f32, f32, _, DeterministicHashState> = Graph::new(
+                ExplicitIntegralIdentifierRegistry::new(16),
+                ExplicitIntegralIdentifierRegistry::new(16),
+            );
+            let ids: Vec<usize> = (0..16)
+                .map(|i| mutators::add_vertex(&mut g, i as f32))
+                .collect();
+            for pair in ids.windows(2) {
+                mutators::add_edge(&mut g, pair[0], pair[1], 1.0);
+            }
+            g
+        };
+
+        let (a, b) = (build(), build());
+        let order = |g: &Graph<usize, f32, f32, _, DeterministicHashState>| {
+            g.vertices().map(|v| *v.id()).collect::<Vec<_>>()
+        };
+        assert_eq!(order(&a), order(&b));
+        assert_eq!(
+            a.edges().map(|e| *e.id()).collect::<Vec<_>>(),
+            b.edges().map(|e| *e.id()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn graph_parallel_edge_policies() {
+        let build = |policy| {
+            let mut g: Graph<usize, f32, f32, _> = Graph::new(
+                ExplicitIntegralIdentifierRegistry::new(2),
+                ExplicitIntegralIdentifierRegistry::new(4),
+            )
+            .with_parallel_edge_policy(policy);
+            let v1 = mutators::add_vertex(&mut g, 0.0);
+            let v2 = mutators::add_vertex(&mut g, 0.0);
+            let e1 = mutators::add_edge(&mut g, v1, v2, 1.0);
+            (g, v1, v2, e1)
+        };
+
+        let (mut g, v1, v2, e1) = build(ParallelEdgePolicy::Allow);
+        let e2 = mutators::add_edge(&mut g, v1, v2, 2.0);
+        assert_ne!(e1, e2);
+        assert_eq!(g.edges_between(v1, v2).count(), 2);
+        assert_eq!(*g.get_edge_between(v1, v2).unwrap().id(), e1);
+        assert!(g.get_edge_between(v2, v1).is_none());
+
+        let (g, v1, v2, _) = build(ParallelEdgePolicy::Reject);
+        let result = mutators::GraphEdgeAdditionMutator::new(v1, 2.0, v2).try_mutate(g);
+        assert_eq!(
+            result.err().map(|(_, failure)| failure),
+            Some(GraphMutationFailure::ParallelEdge(v1, v2))
+        );
+
+        let (mut g, v1, v2, e1) = build(ParallelEdgePolicy::Replace);
+        let e2 = mutators::add_edge(&mut g, v1, v2, 2.0);
+        assert_eq!(e1, e2);
+        assert_eq!(g.edge_count(), 1);
+        assert_eq!(*g.get_edge_between(v1, v2).unwrap().data(), 2.0);
+
+        // Undoing a replacement restores the data instead of removing the edge.
+        let mut replace = mutators::GraphEdgeAdditionMutator::new(v1, 3.0, v2);
+        let g = replace.try_mutate(g).ok().unwrap();
+        assert_eq!(*g.get_edge_between(v1, v2).unwrap().data(), 3.0);
+        let g = replace.undo(g);
+        assert_eq!(g.edge_count(), 1);
+        assert_eq!(*g.get_edge_between(v1, v2).unwrap().data(), 2.0);
+    }
+
+    #[test]
+    fn graph_dot_export() {
+        let mut g: Graph<usize, &str, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(2),
+            ExplicitIntegralIdentifierRegistry::new(1),
+        );
+        let base = mutators::add_vertex(&mut g, "base");
+        let tool = mutators::add_vertex(&mut g, "tool \"tcp\"");
+        mutators::add_edge(&mut g, base, tool, 0.5);
+
+        let dot = export::to_dot(&g, |name| name.to_string(), |w| format!("{w}")).unwrap();
+
+        assert!(dot.starts_with("digraph {\n"));
+        assert!(dot.ends_with("}\n"));
+        assert!(dot.contains(&format!("\"{base}\" [label=\"base\"];")));
+        assert!(dot.contains(&format!("\"{tool}\" [label=\"tool \\\"tcp\\\"\"];")));
+        assert!(dot.contains(&format!("\"{base}\" -> \"{tool}\" [label=\"0.5\"];")));
+    }
+
+    #[test]
+    fn graph_dot_export_escapes_ids() {
+        // Identifiers whose names need escaping in DOT.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        struct QuotedId(usize);
+
+        impl std::fmt::Display for QuotedId {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "\"{}\"\\", self.0)
+            }
+        }
+
+        #[derive(Clone)]
+        struct QuotedRegistry(ExplicitIntegralIdentifierRegistry);
+
+        impl IdentifierRegistry<QuotedId> for QuotedRegistry {
+            type Identifier = QuotedId;
+
+            fn null_registry() -> Self {
+                QuotedRegistry(ExplicitIntegralIdentifierRegistry::null_registry())
+            }
+
+            fn acquire_id(&mut self) -> Result<QuotedId, IdentifierRegistryFailure> {
+                self.0.acquire_id().map(QuotedId)
+            }
+
+            fn release_id(&mut self, id: QuotedId) -> Result<(), IdentifierRegistryFailure> {
+                self.0.release_id(id.0)
+            }
+        }
+
+        let mut g: Graph<QuotedId, f32, f32, _> = Graph::new(
+            QuotedRegistry(ExplicitIntegralIdentifierRegistry::new(2)),
+            QuotedRegistry(ExplicitIntegralIdentifierRegistry::new(1)),
+        );
+        let v0 = mutators::add_vertex(&mut g, 0.0);
+        let v1 = mutators::add_vertex(&mut g, 1.0);
+        mutators::add_edge(&mut g, v0, v1, 0.5);
+
+        let dot = export::to_dot(&g, |d| d.to_string(), |w| w.to_string()).unwrap();
+        assert!(dot.contains(r#"    "\"0\"\\" [label="0"];"#));
+        assert!(dot.contains(r#"    "\"0\"\\" -> "\"1\"\\" [label="0.5"];"#));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn graph_serde_round_trip() {
+        let mut g: Graph<usize, String, f32, ExplicitIntegralIdentifierRegistry> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(2),
+            ExplicitIntegralIdentifierRegistry::new(1),
+        );
+        let v1 = mutators::add_vertex(&mut g, "a".to_string());
+        let v2 = mutators::add_vertex(&mut g, "b".to_string());
+        let e1 = mutators::add_edge(&mut g, v1, v2, 0.5);
+
+        let json = serde_json::to_string(&g).unwrap();
+        let mut h: Graph<usize, String, f32, ExplicitIntegralIdentifierRegistry> =
+            serde_json::from_str(&json).unwrap();
+
+        assert_eq!(h.vertex_count(), 2);
+        assert_eq!(h.edge_count(), 1);
+        assert_eq!(h.edge_endpoints(e1), Some((v1, v2)));
+        assert_eq!(h.vertex(v2).map(|v| v.data().as_str()), Some("b"));
+        assert!(h.is_adjacent(v1, v2));
+
+        let v3 = mutators::add_vertex(&mut h, "c".to_string());
+        assert!(v3 != v1 && v3 != v2);
+
+        // An adjacency list naming an edge that is not in the graph.
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        value["forward_edges"][v1.to_string()] = serde_json::json!([[e1, v2], [7, v2]]);
+        let error = serde_json::from_value::<
+            Graph<usize, String, f32, ExplicitIntegralIdentifierRegistry>,
+        >(value)
+        .err()
+        .unwrap();
+        assert!(error.to_string().contains("Edge id 7 is referenced"));
+    }
+
+    #[test]
+    fn graph_validate() {
+        let mut g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(3),
+            ExplicitIntegralIdentifierRegistry::new(2),
+        );
+        let v1 = mutators::add_vertex(&mut g, 0.0);
+        let v2 = mutators::add_vertex(&mut g, 0.0);
+        let e1 = mutators::add_edge(&mut g, v1, v2, 1.0);
+        let e2 = mutators::add_edge(&mut g, v2, v1, 1.0);
+        assert_eq!(g.validate(), Ok(()));
+
+        g.backward_edges.get_mut(&v2).unwrap().clear();
+        g.forward_edges.insert(42, vec![(e2, v1)]);
+        g.vertex_id_registry.release_id(v1).unwrap();
+
+        let violations = g.validate().unwrap_err();
+        assert!(violations.contains(&GraphViolation::UnmirroredEdge(e1)));
+        assert!(violations.contains(&GraphViolation::UnmirroredEdge(e2)));
+        assert!(violations.contains(&GraphViolation::UnknownVertex(42)));
+        assert!(violations.contains(&GraphViolation::VertexIdNotReserved(v1)));
+        assert!(!violations.contains(&GraphViolation::VertexIdNotReserved(v2)));
+    }
+
+    #[test]
+    fn graph_diff() {
+        let mut old: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(4),
+            ExplicitIntegralIdentifierRegistry::new(4),
+        );
+        let v1 = mutators::add_vertex(&mut old, 1.0);
+        let v2 = mutators::add_vertex(&mut old, 2.0);
+        let v3 = mutators::add_vertex(&mut old, 3.0);
+        let e1 = mutators::add_edge(&mut old, v1, v2, 1.0);
+        let e2 = mutators::add_edge(&mut old, v2, v3, 1.0);
+        assert!(diff(&old, &old).is_empty());
+
+        let mut new = old.clone();
+        let v4 = mutators::add_vertex(&mut new, 4.0);
+        let e3 = mutators::add_edge(&mut new, v3, v4, 1.0);
+        mutators::map_vertex(&mut new, v2, |d| d * 2.0);
+        mutators::map_edge(&mut new, e1, |w| w + 1.0);
+
+        let changes = diff(&old, &new);
+        assert_eq!(changes.added_vertices, vec![v4]);
+        assert_eq!(changes.changed_vertices, vec![v2]);
+        assert!(changes.removed_vertices.is_empty());
+        assert_eq!(changes.added_edges, vec![e3]);
+        assert_eq!(changes.changed_edges, vec![e1]);
+        assert!(changes.removed_edges.is_empty());
+
+        let reverse = diff(&new, &old);
+        assert_eq!(reverse.removed_vertices, vec![v4]);
+        assert_eq!(reverse.removed_edges, vec![e3]);
+        assert!(!reverse.changed_edges.contains(&e2));
+    }
+
+    #[test]
+    fn graph_mutable_data_access() {
+        let mut g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(2),
+            ExplicitIntegralIdentifierRegistry::new(1),
+        );
+        let v1 = mutators::add_vertex(&mut g, 1.0);
+        let v2 = mutators::add_vertex(&mut g, 2.0);
+        let e1 = mutators::add_edge(&mut g, v1, v2, 0.5);
+
+        *g.get_vertex_mut(v2).unwrap() += 1.0;
+        *g.get_edge_mut(e1).unwrap() = 4.0;
+
+        assert_eq!(*g.vertex(v2).unwrap().data(), 3.0);
+        assert_eq!(*g.edge(e1).unwrap().data(), 4.0);
+        assert!(g.get_vertex_mut(42).is_none());
+        assert!(g.get_edge_mut(42).is_none());
+    }
+
+    #[test]
+    fn graph_select_edges() {
+        let mut g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(3),
+            ExplicitIntegralIdentifierRegistry::new(3),
+        );
+        let v1 = mutators::add_vertex(&mut g, 1.0);
+        let v2 = mutators::add_vertex(&mut g, 2.0);
+        let v3 = mutators::add_vertex(&mut g, 3.0);
+        let e1 = mutators::add_edge(&mut g, v1, v2, 1.0);
+        let e2 = mutators::add_edge(&mut g, v2, v3, 0.5);
+        let e3 = mutators::add_edge(&mut g, v3, v1, 1.0);
+
+        let non_unit: Vec<usize> = g
+            .select_edges(|w| *w != 1.0)
+            .iter()
+            .map(|e| *e.id())
+            .collect();
+        assert_eq!(non_unit, vec![e2]);
+
+        let mut out_of_heavy: Vec<usize> = g
+            .select_edges_between(|d| *d >= 2.0, |_| true)
+            .unwrap()
+            .iter()
+            .map(|e| *e.id())
+            .collect();
+        out_of_heavy.sort();
+        assert_eq!(out_of_heavy, vec![e2, e3]);
+
+        let into_first: Vec<usize> = g
+            .select_edges_between(|_| true, |d| *d == 1.0)
+            .unwrap()
+            .iter()
+            .map(|e| *e.id())
+            .collect();
+        assert_eq!(into_first, vec![e3]);
+        assert!(!into_first.contains(&e1));
+    }
+
+    #[test]
+    fn graph_display_summary() {
+        let mut g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(3),
+            ExplicitIntegralIdentifierRegistry::new(2),
+        );
+        assert_eq!(g.to_string(), "Graph (0 vertices, 0 edges)");
+
+        let v1 = mutators::add_vertex(&mut g, 1.0);
+        let v2 = mutators::add_vertex(&mut g, 2.0);
+        mutators::add_vertex(&mut g, 3.0);
+        mutators::add_edge(&mut g, v1, v2, 1.0);
+        assert_eq!(format!("{g}"), "Graph (3 vertices, 1 edges)");
+    }
+
+    #[test]
+    fn graph_breadth_first_discovery() {
+        struct DiscoveryRecorder {
+            discoveries: Vec<(usize, Discovery<usize>)>,
+        }
+
+        impl<'a> TraversalVisitor<'a, usize, f32, f32> for DiscoveryRecorder {
+            fn reset(&mut self) {
+                self.discoveries.clear()
+            }
+
+            fn visit_vertex(
+                &mut self,
+                vertex: &'a VertexDescriptor<usize, f32>,
+                discovery: Discovery<usize>,
+            ) {
+                self.discoveries.push((*vertex.id(), discovery))
+            }
+
+            fn visit_edge(&mut self, _: usize, _: &'a EdgeDescriptor<usize, f32>, _: usize) {}
+        }
+
+        let mut g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(4),
+            ExplicitIntegralIdentifierRegistry::new(4),
+        );
+        let v1 = mutators::add_vertex(&mut g, 0.0);
+        let v2 = mutators::add_vertex(&mut g, 0.0);
+        let v3 = mutators::add_vertex(&mut g, 0.0);
+        let v4 = mutators::add_vertex(&mut g, 0.0);
+        mutators::add_edge(&mut g, v1, v2, 1.0);
+        mutators::add_edge(&mut g, v2, v3, 1.0);
+        mutators::add_edge(&mut g, v3, v4, 1.0);
+
+        let mut visitor = DiscoveryRecorder {
+            discoveries: Vec::new(),
+        };
+        breadth_first_discovery(&g, v1, &mut visitor).unwrap();
+
+        let expected: Vec<(usize, Discovery<usize>)> = [v1, v2, v3, v4]
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                let predecessor = if i == 0 {
+                    None
+                } else {
+                    Some([v1, v2, v3][i - 1])
+                };
+                (
+                    *v,
+                    Discovery {
+                        depth: i,
+                        predecessor,
+                        index: i,
+                    },
+                )
+            })
+            .collect();
+        assert_eq!(visitor.discoveries, expected);
+
+        assert!(depth_limited_discovery(&g, v2, 1, &mut visitor).unwrap());
+        assert_eq!(visitor.discoveries.len(), 2);
+        assert_eq!(visitor.discoveries[1].1.predecessor, Some(v2));
+    }
+
+    #[test]
+    fn graph_errors_chain_to_registry_failures() {
+        let g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::null_registry(),
+            ExplicitIntegralIdentifierRegistry::null_registry(),
+        );
+        let failure = mutators::GraphVertexAdditionMutator::new(0.0)
+            .try_mutate(g)
+            .err()
+            .unwrap()
+            .1;
+        assert_eq!(
+            failure,
+            GraphMutationFailure::IdentifierUnavailable(
+                IdentifierRegistryFailure::OutOfIdentifiers
+            )
+        );
+
+        let error: Box<dyn std::error::Error> = Box::new(failure);
+        let source = error.source().unwrap();
+        assert_eq!(
+            source.to_string(),
+            IdentifierRegistryFailure::OutOfIdentifiers.to_string()
+        );
+    }
+
+    #[test]
+    fn graph_traversal_subtree_pruning() {
+        struct ObstacleAvoider {
+            visited: Vec<usize>,
+        }
+
+        impl<'a> GraphVisitor<'a, usize, f32, f32> for ObstacleAvoider {
+            fn reset(&mut self) {
+                self.visited.clear()
+            }
+
+            fn visit_vertex(&mut self, vertex: &'a VertexDescriptor<usize, f32>) {
+                self.visited.push(*vertex.id())
+            }
+
+            fn visit_edge(&mut self, _: usize, _: &'a EdgeDescriptor<usize, f32>, _: usize) {}
+
+            fn should_expand(&mut self, vertex: &'a VertexDescriptor<usize, f32>) -> bool {
+                *vertex.data() >= 0.0
+            }
+        }
+
+        // v1 -> obstacle -> v3, v1 -> v2 -> v4
+        let mut g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(5),
+            ExplicitIntegralIdentifierRegistry::new(4),
+        );
+        let v1 = mutators::add_vertex(&mut g, 0.0);
+        let obstacle = mutators::add_vertex(&mut g, -1.0);
+        let v2 = mutators::add_vertex(&mut g, 0.0);
+        let v3 = mutators::add_vertex(&mut g, 0.0);
+        let v4 = mutators::add_vertex(&mut g, 0.0);
+        mutators::add_edge(&mut g, v1, obstacle, 1.0);
+        mutators::add_edge(&mut g, obstacle, v3, 1.0);
+        mutators::add_edge(&mut g, v1, v2, 1.0);
+        mutators::add_edge(&mut g, v2, v4, 1.0);
+
+        let mut visitor = ObstacleAvoider {
+            visited: Vec::new(),
+        };
+        breadth_first_traversal(&g, v1, &mut visitor).unwrap();
+        assert_eq!(visitor.visited, vec![v1, obstacle, v2, v4]);
+
+        best_first_traversal(&g, v1, |_, _, _| 0.0, &mut visitor).unwrap();
+        assert!(visitor.visited.contains(&obstacle));
+        assert!(visitor.visited.contains(&v4));
+        assert!(!visitor.visited.contains(&v3));
+    }
+
+    #[test]
+    fn graph_builder() {
+        let builder::BuiltGraph { graph: g, ids } = builder::GraphBuilder::new(
+            ExplicitIntegralIdentifierRegistry::new(2),
+            ExplicitIntegralIdentifierRegistry::new(2),
+        )
+        .vertex("base", 0.0)
+        .vertex("tool", 1.0)
+        .edge("base", 0.5, "tool")
+        .build()
+        .unwrap();
+        let g: Graph<usize, f32, f32, _> = g;
+
+        assert_eq!(g.vertex_count(), 2);
+        assert!(g.is_adjacent(ids["base"], ids["tool"]));
+        assert_eq!(*g.vertex(ids["tool"]).unwrap().data(), 1.0);
+
+        let duplicate = builder::GraphBuilder::<_, usize, f32, f32, _>::new(
+            ExplicitIntegralIdentifierRegistry::new(2),
+            ExplicitIntegralIdentifierRegistry::new(2),
+        )
+        .vertex("base", 0.0)
+        .vertex("base", 1.0)
+        .build();
+        assert_eq!(
+            duplicate.err(),
+            Some(builder::GraphBuildFailure::DuplicateVertex("base"))
+        );
+
+        let unknown = builder::GraphBuilder::<_, usize, f32, f32, _>::new(
+            ExplicitIntegralIdentifierRegistry::new(2),
+            ExplicitIntegralIdentifierRegistry::new(2),
+        )
+        .vertex("base", 0.0)
+        .edge("base", 0.5, "tool")
+        .build();
+        assert_eq!(
+            unknown.err(),
+            Some(builder::GraphBuildFailure::UnknownVertex("tool"))
+        );
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn graph_parallel_breadth_first_traversal() {
+        use std::sync::Mutex;
+
+        struct DepthRecorder {
+            depths: Mutex<Vec<(usize, usize)>>,
+            edges: Mutex<Vec<usize>>,
+        }
+
+        impl<'a> parallel::ParallelGraphVisitor<'a, usize, f32, f32> for DepthRecorder {
+            fn visit_vertex(&self, vertex: &'a VertexDescriptor<usize, f32>, depth: usize) {
+                self.depths.lock().unwrap().push((*vertex.id(), depth))
+            }
+
+            fn visit_edge(&self, _: usize, edge: &'a EdgeDescriptor<usize, f32>, _: usize) {
+                self.edges.lock().unwrap().push(*edge.id())
+            }
+        }
+
+        // Diamond v1 -> {v2, v3} -> v4, plus an unreachable v5.
+        let mut g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(5),
+            ExplicitIntegralIdentifierRegistry::new(4),
+        );
+        let v: Vec<usize> = (0..5).map(|_| mutators::add_vertex(&mut g, 0.0)).collect();
+        mutators::add_edge(&mut g, v[0], v[1], 1.0);
+        mutators::add_edge(&mut g, v[0], v[2], 1.0);
+        mutators::add_edge(&mut g, v[1], v[3], 1.0);
+        mutators::add_edge(&mut g, v[2], v[3], 1.0);
+
+        let visitor = DepthRecorder {
+            depths: Mutex::new(Vec::new()),
+            edges: Mutex::new(Vec::new()),
+        };
+        parallel::parallel_breadth_first_traversal(&g, v[0], &visitor).unwrap();
+
+        let mut depths = visitor.depths.into_inner().unwrap();
+        depths.sort();
+        assert_eq!(depths, vec![(v[0], 0), (v[1], 1), (v[2], 1), (v[3], 2)]);
+        assert_eq!(visitor.edges.into_inner().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn graph_find_path_avoiding() {
+        use std::collections::HashSet;
+
+        // v1 -> v2 -> v4 and v1 -> v3 -> v4
+        let mut g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(4),
+            ExplicitIntegralIdentifierRegistry::new(4),
+        );
+        let v1 = mutators::add_vertex(&mut g, 0.0);
+        let v2 = mutators::add_vertex(&mut g, 0.0);
+        let v3 = mutators::add_vertex(&mut g, 0.0);
+        let v4 = mutators::add_vertex(&mut g, 0.0);
+        let e12 = mutators::add_edge(&mut g, v1, v2, 1.0);
+        mutators::add_edge(&mut g, v2, v4, 1.0);
+        let e13 = mutators::add_edge(&mut g, v1, v3, 1.0);
+        mutators::add_edge(&mut g, v3, v4, 1.0);
+
+        let ids = |walk: Walk<usize, f32, f32>| -> Vec<usize> {
+            walk.vertices().map(|v| *v.id()).collect()
+        };
+        let none = HashSet::new();
+
+        let walk =
+            pathfinding::find_path_avoiding(&g, v1, v4, &HashSet::from([v2]), &none).unwrap();
+        assert_eq!(walk.map(ids), Some(vec![v1, v3, v4]));
+
+        let walk =
+            pathfinding::find_path_avoiding(&g, v1, v4, &none, &HashSet::from([e13])).unwrap();
+        assert_eq!(walk.map(ids), Some(vec![v1, v2, v4]));
+
+        let blocked = HashSet::from([e12, e13]);
+        assert!(pathfinding::find_path_avoiding(&g, v1, v4, &none, &blocked)
+            .unwrap()
+            .is_none());
+        assert!(
+            pathfinding::find_path_avoiding(&g, v1, v4, &HashSet::from([v4]), &none)
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(
+            pathfinding::find_path_avoiding_ids(&g, v1, v4, &HashSet::from([v3]), &none)
+                .unwrap()
+                .map(|walk| walk.vertices),
+            Some(vec![v1, v2, v4])
+        );
+        assert_eq!(g.vertex_count(), 4);
+
+        // A walk through a vertex missing from the graph is reported, not
+        // mistaken for there being no walk.
+        let mut missing_vertex = g.clone();
+        missing_vertex.vertices.remove(&v2);
+        assert_eq!(
+            pathfinding::find_path_avoiding(&missing_vertex, v1, v4, &HashSet::from([v3]), &none)
+                .err(),
+            Some(GraphTraversalFailure::IllFormed(
+                GraphViolation::UnknownVertex(v2)
+            ))
+        );
+
+        let mut missing_edge = g.clone();
+        missing_edge.edges.remove(&e12);
+        assert_eq!(
+            pathfinding::find_path_avoiding_ids(&missing_edge, v1, v4, &HashSet::from([v3]), &none),
+            Err(GraphTraversalFailure::IllFormed(
+                GraphViolation::UnknownEdge(e12)
+            ))
+        );
+    }
+
+    #[test]
+    fn graph_owned_walk_ids() {
+        let mut g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(3),
+            ExplicitIntegralIdentifierRegistry::new(2),
+        );
+        let v1 = mutators::add_vertex(&mut g, 0.0);
+        let v2 = mutators::add_vertex(&mut g, 0.0);
+        let e1 = mutators::add_edge(&mut g, v1, v2, 1.0);
+
+        let plan = pathfinding::find_path(&g, v1, v2)
+            .unwrap()
+            .unwrap()
+            .to_ids();
+        assert_eq!(
+            plan,
+            WalkIds {
+                vertices: vec![v1, v2],
+                edges: vec![e1]
+            }
+        );
+
+        // The graph can be mutated while the plan is held.
+        mutators::add_vertex(&mut g, 0.0);
+        mutators::map_edge(&mut g, e1, |w| w * 2.0);
+
+        let walk = plan.resolve(&g).unwrap();
+        assert_eq!(walk.total_cost(0.0, |c, w| c + w), 2.0);
+
+        let stale = WalkIds {
+            vertices: vec![v2, v1],
+            edges: vec![e1],
+        };
+        assert!(stale.resolve(&g).is_none());
+    }
+
+    #[test]
+    fn graph_fallible_mutations() {
+        let mut g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(2),
+            ExplicitIntegralIdentifierRegistry::new(2),
+        );
+
+        let v1 = mutators::try_add_vertex(&mut g, 1.0).unwrap();
+        let v2 = mutators::try_add_vertex(&mut g, 2.0).unwrap();
+        let e12 = mutators::try_add_edge(&mut g, v1, v2, 3.0).unwrap();
+
+        assert_eq!(
+            mutators::try_add_edge(&mut g, v1, 7, 1.0),
+            Err(GraphMutationFailure::VertexNotFound(7))
+        );
+        assert_eq!(
+            mutators::try_map_vertex(&mut g, 7, |d| d + 1.0),
+            Err(GraphMutationFailure::VertexNotFound(7))
+        );
+        assert_eq!(
+            mutators::try_map_edge(&mut g, 7, |d| d + 1.0),
+            Err(GraphMutationFailure::EdgeNotFound(7))
+        );
+        assert_eq!(g.vertex_count(), 2);
+        assert_eq!(g.edge_count(), 1);
+
+        mutators::try_map_edge(&mut g, e12, |d| d * 2.0).unwrap();
+        assert_eq!(*g.edge(e12).unwrap().data(), 6.0);
+    }
+
+    #[test]
+    fn graph_ill_formed_queries_report_violations() {
+        let mut g: Graph<usize, f32, f32, _> = Graph::new(
+            ExplicitIntegralIdentifierRegistry::new(2),
+            ExplicitIntegralIdentifierRegistry::new(1),
+        );
+        let v1 = mutators::add_vertex(&mut g, 1.0);
+        let v2 = mutators::add_vertex(&mut g, 2.0);
+        let e12 = mutators::add_edge(&mut g, v1, v2, 3.0);
+
+        let mut dangling = g.clone();
+        dangling.vertices.remove(&v2);
+        assert_eq!(
+            csr::CsrGraph::from_graph(&dangling).err(),
+            Some(GraphViolation::UnknownVertex(v2))
+        );
+        assert_eq!(
+            dangling.select_edges_between(|_| true, |_| true).err(),
+            Some(GraphViolation::UnknownVertex(v2))
+        );
+
+        g.edge_endpoints.remove(&e12);
+        assert_eq!(
+            export::to_dot(&g, |d| d.to_string(), |w| w.to_string()),
+            Err(GraphViolation::UnmirroredEdge(e12))
+        );
+        assert_eq!(
+            g.select_edges_between(|_| true, |_| true).err(),
+            Some(GraphViolation::UnmirroredEdge(e12))
+        );
+    }
+
     impl<'a> GraphVisitor<'a, usize, f32, f32> for CountingGraphVisitor {
         fn reset(&mut self) {
             self.vertex_count = 0;
@@ -0,0 +1,312 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+//! Joint module.
+//!
+//! Joints of articulated mechanisms. A joint connects a parent frame to a
+//! child frame through a fixed origin transformation followed by a motion
+//! along (prismatic) or about (revolute, continuous) a unit axis, which is
+//! expressed in the joint's origin frame and parameterized by the joint's
+//! current value.
+
+use crate::math::arrayalgebra::{make_array_vector, ArrayMatrix, ArrayVector};
+use crate::math::rotations::matrix_from_axis_angle;
+use crate::math::spatial::{Twist, SE3};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Length below which a joint axis is treated as zero.
+const AXIS_TOLERANCE: f32 = 1e-6;
+
+/// Version of the serialized joint format, bumped whenever it changes in a way
+/// older readers cannot handle.
+#[cfg(feature = "serde")]
+const FORMAT_VERSION: u32 = 1;
+
+/// Joint Failures.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum JointFailure {
+    /// Reported when a value outside the joint's limits is requested.
+    OutOfLimits(f32),
+
+    /// Reported when the value of a fixed joint is set.
+    FixedJoint,
+}
+
+impl Display for JointFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JointFailure::OutOfLimits(value) => {
+                write!(f, "Joint value {value} lies outside the joint's limits.")
+            }
+            JointFailure::FixedJoint => write!(f, "Fixed joints have no value to set."),
+        }
+    }
+}
+
+impl Error for JointFailure {}
+
+/// Kind of motion a joint permits, with its limits where it has any.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum JointKind {
+    /// Rotation about the axis, by an angle in [lower, upper] radians.
+    Revolute { lower: f32, upper: f32 },
+
+    /// Unlimited rotation about the axis, e.g. a wheel.
+    Continuous,
+
+    /// Translation along the axis, by a distance in [lower, upper].
+    Prismatic { lower: f32, upper: f32 },
+
+    /// No motion; the child frame is rigidly attached at the origin.
+    Fixed,
+}
+
+impl JointKind {
+    /// Checks if the kind's limits, if it has any, are ordered and not NaN.
+    pub fn has_valid_limits(&self) -> bool {
+        match *self {
+            JointKind::Revolute { lower, upper } | JointKind::Prismatic { lower, upper } => {
+                lower <= upper
+            }
+            JointKind::Continuous | JointKind::Fixed => true,
+        }
+    }
+}
+
+/// Joint.
+///
+/// Maps its value to the transformation from the child frame to the parent
+/// frame, `origin * motion(value)`.
+///
+/// With the `serde` feature enabled, joints serialize with a format version
+/// and deserialize through `Joint::new` and `Joint::set_value`, so a snapshot
+/// with a zero axis, inverted or NaN limits, or a value outside the limits is
+/// rejected.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "JointRepr", try_from = "JointRepr")
+)]
+pub struct Joint {
+    kind: JointKind,
+    axis: ArrayVector<f32, 3>,
+    origin: SE3,
+    value: f32,
+}
+
+impl Joint {
+    /// Builds a joint of the given kind at value zero, or at the nearest limit
+    /// if zero lies outside them. The axis need not be of unit length; a
+    /// (near) zero axis, or limits that are inverted or NaN, are reported as
+    /// `None`.
+    pub fn new(kind: JointKind, axis: ArrayVector<f32, 3>, origin: SE3) -> Option<Self> {
+        if !kind.has_valid_limits() {
+            return None;
+        }
+        let axis = match kind {
+            JointKind::Fixed => make_array_vector([0.0, 0.0, 1.0]),
+            _ if axis.norm() < AXIS_TOLERANCE => return None,
+            _ => axis.normalize()?,
+        };
+        let value = match kind {
+            JointKind::Revolute { lower, upper } | JointKind::Prismatic { lower, upper } => {
+                0.0f32.clamp(lower, upper)
+            }
+            _ => 0.0,
+        };
+
+        Some(Joint {
+            kind,
+            axis,
+            origin,
+            value,
+        })
+    }
+
+    pub fn revolute(
+        axis: ArrayVector<f32, 3>,
+        origin: SE3,
+        lower: f32,
+        upper: f32,
+    ) -> Option<Self> {
+        Joint::new(JointKind::Revolute { lower, upper }, axis, origin)
+    }
+
+    pub fn continuous(axis: ArrayVector<f32, 3>, origin: SE3) -> Option<Self> {
+        Joint::new(JointKind::Continuous, axis, origin)
+    }
+
+    pub fn prismatic(
+        axis: ArrayVector<f32, 3>,
+        origin: SE3,
+        lower: f32,
+        upper: f32,
+    ) -> Option<Self> {
+        Joint::new(JointKind::Prismatic { lower, upper }, axis, origin)
+    }
+
+    pub fn fixed(origin: SE3) -> Self {
+        Joint::new(JointKind::Fixed, make_array_vector([0.0, 0.0, 1.0]), origin)
+            .expect("Fixed joints do not need an axis.")
+    }
+
+    pub fn kind(&self) -> JointKind {
+        self.kind
+    }
+
+    /// Returns the unit axis, in the origin frame.
+    pub fn axis(&self) -> ArrayVector<f32, 3> {
+        self.axis
+    }
+
+    pub fn origin(&self) -> SE3 {
+        self.origin
+    }
+
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Checks if the value lies within the joint's limits.
+    pub fn within_limits(&self, value: f32) -> bool {
+        match self.kind {
+            JointKind::Revolute { lower, upper } | JointKind::Prismatic { lower, upper } => {
+                lower <= value && value <= upper
+            }
+            JointKind::Continuous => value.is_finite(),
+            JointKind::Fixed => value == 0.0,
+        }
+    }
+
+    /// Sets the joint's value, or fails if it lies outside the joint's limits
+    /// or the joint is fixed.
+    pub fn set_value(&mut self, value: f32) -> Result<(), JointFailure> {
+        if self.kind == JointKind::Fixed {
+            return Err(JointFailure::FixedJoint);
+        }
+        if !self.within_limits(value) {
+            return Err(JointFailure::OutOfLimits(value));
+        }
+
+        self.value = value;
+        Ok(())
+    }
+
+    /// Returns the transformation from the child frame to the parent frame at
+    /// the joint's current value.
+    pub fn transform(&self) -> SE3 {
+        self.transform_at(self.value)
+    }
+
+    /// Returns the transformation from the child frame to the parent frame at
+    /// the given value, regardless of the joint's limits.
+    pub fn transform_at(&self, value: f32) -> SE3 {
+        let motion = match self.kind {
+            JointKind::Revolute { .. } | JointKind::Continuous => SE3::new(
+                matrix_from_axis_angle(self.axis, value),
+                ArrayVector::zeros(),
+            ),
+            JointKind::Prismatic { .. } => SE3::new(ArrayMatrix::identity(), self.axis * value),
+            JointKind::Fixed => SE3::identity(),
+        };
+
+        self.origin * motion
+    }
+
+    /// Returns the twist of the child frame, in the child frame, per unit rate
+    /// of change of the joint's value, i.e. the joint's column of the body
+    /// Jacobian. Zero for fixed joints.
+    pub fn unit_twist(&self) -> Twist {
+        let zero = ArrayVector::zeros();
+        match self.kind {
+            JointKind::Revolute { .. } | JointKind::Continuous => Twist::new(self.axis, zero),
+            JointKind::Prismatic { .. } => Twist::new(zero, self.axis),
+            JointKind::Fixed => Twist::zero(),
+        }
+    }
+}
+
+/// Serialized form of `Joint`.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct JointRepr {
+    version: u32,
+    kind: JointKind,
+    axis: ArrayVector<f32, 3>,
+    origin: SE3,
+    value: f32,
+}
+
+#[cfg(feature = "serde")]
+impl From<Joint> for JointRepr {
+    fn from(joint: Joint) -> Self {
+        JointRepr {
+            version: FORMAT_VERSION,
+            kind: joint.kind,
+            axis: joint.axis,
+            origin: joint.origin,
+            value: joint.value,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<JointRepr> for Joint {
+    type Error = String;
+
+    fn try_from(repr: JointRepr) -> Result<Self, Self::Error> {
+        if repr.version != FORMAT_VERSION {
+            return Err(format!(
+                "Unsupported joint format version {}; expected {FORMAT_VERSION}.",
+                repr.version
+            ));
+        }
+
+        if !repr.kind.has_valid_limits() {
+            return Err(format!(
+                "Joint limits of {:?} are inverted or NaN.",
+                repr.kind
+            ));
+        }
+
+        let mut joint = Joint::new(repr.kind, repr.axis, repr.origin)
+            .ok_or_else(|| "Joint axis must not be zero.".to_string())?;
+        if repr.kind != JointKind::Fixed {
+            joint
+                .set_value(repr.value)
+                .map_err(|failure| failure.to_string())?;
+        }
+
+        Ok(joint)
+    }
+}
@@ -0,0 +1,105 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+//! nalgebra interoperability module.
+//!
+//! Conversions between this crate's array-backed algebra and rigid transforms
+//! and the corresponding nalgebra types, available with the `nalgebra`
+//! feature. Every conversion copies the (statically sized) entries, so the
+//! two sides never share storage.
+
+use crate::math::algebra::Scalar;
+use crate::math::arrayalgebra::{make_array_matrix, ArrayMatrix, ArrayVector};
+use crate::math::rotations::Quaternion;
+use crate::math::spatial::SE3;
+use nalgebra::{Isometry3, SMatrix, SVector, Translation3, UnitQuaternion};
+
+impl<F: Scalar + nalgebra::Scalar, const N: usize> From<ArrayVector<F, N>> for SVector<F, N> {
+    fn from(vector: ArrayVector<F, N>) -> Self {
+        SVector::from_fn(|i, _| vector[i])
+    }
+}
+
+impl<F: Scalar + nalgebra::Scalar, const N: usize> From<SVector<F, N>> for ArrayVector<F, N> {
+    fn from(vector: SVector<F, N>) -> Self {
+        vector.iter().copied().collect()
+    }
+}
+
+impl<F: Scalar + nalgebra::Scalar, const R: usize, const C: usize> From<ArrayMatrix<F, R, C>>
+    for SMatrix<F, R, C>
+{
+    fn from(matrix: ArrayMatrix<F, R, C>) -> Self {
+        SMatrix::from_fn(|row, column| matrix.get(row, column))
+    }
+}
+
+impl<F: Scalar + nalgebra::Scalar, const R: usize, const C: usize> From<SMatrix<F, R, C>>
+    for ArrayMatrix<F, R, C>
+{
+    fn from(matrix: SMatrix<F, R, C>) -> Self {
+        let mut rows = [[F::additive_unit(); C]; R];
+
+        for (i, row) in rows.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate() {
+                *entry = matrix[(i, j)];
+            }
+        }
+
+        make_array_matrix(rows)
+    }
+}
+
+/// Normalizes the quaternion, as nalgebra's unit quaternion requires.
+impl From<Quaternion> for UnitQuaternion<f32> {
+    fn from(q: Quaternion) -> Self {
+        UnitQuaternion::from_quaternion(nalgebra::Quaternion::new(q.w, q.x, q.y, q.z))
+    }
+}
+
+impl From<UnitQuaternion<f32>> for Quaternion {
+    fn from(q: UnitQuaternion<f32>) -> Self {
+        Quaternion::new(q.w, q.i, q.j, q.k)
+    }
+}
+
+impl From<SE3> for Isometry3<f32> {
+    fn from(pose: SE3) -> Self {
+        Isometry3::from_parts(
+            Translation3::from(SVector::from(pose.translation())),
+            pose.quaternion().into(),
+        )
+    }
+}
+
+impl From<Isometry3<f32>> for SE3 {
+    fn from(pose: Isometry3<f32>) -> Self {
+        SE3::from_quaternion(&pose.rotation.into(), pose.translation.vector.into())
+    }
+}
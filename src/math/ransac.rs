@@ -0,0 +1,223 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+//! Random Sample Consensus module.
+//!
+//! Provides a generic RANSAC/MSAC estimator that is parameterized by closures
+//! fitting a model to a minimal sample and measuring the residual of a datum
+//! with respect to a model. Any robust fitting problem (lines, planes, rigid
+//! transforms, ...) can reuse it instead of shipping its own sampling loop.
+
+/// Scoring rule used to compare candidate models.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConsensusScoring {
+    /// Classic RANSAC: the model with the most inliers wins.
+    Ransac,
+
+    /// M-estimator SAC: inliers cost their squared residual and outliers cost
+    /// the squared threshold; the model with the lowest total cost wins.
+    Msac,
+}
+
+/// Parameters of a consensus estimation.
+#[derive(Clone, Copy, Debug)]
+pub struct ConsensusParameters {
+    /// Number of minimal samples to draw.
+    pub iterations: usize,
+
+    /// Number of data in a minimal sample.
+    pub sample_size: usize,
+
+    /// Largest residual for which a datum is considered an inlier.
+    pub inlier_threshold: f32,
+
+    /// Scoring rule used to compare candidate models.
+    pub scoring: ConsensusScoring,
+
+    /// Seed of the random number generator used to draw samples. Estimation
+    /// is deterministic for a given seed.
+    pub seed: u64,
+}
+
+/// Result of a consensus estimation.
+#[derive(Clone, Debug)]
+pub struct ConsensusEstimate<Model> {
+    /// Model refitted to all inliers of the best candidate, or the candidate
+    /// itself if refitting made it worse.
+    pub model: Model,
+
+    /// Indices of the data that are inliers of the model.
+    pub inliers: Vec<usize>,
+
+    /// Cost of the model under the chosen scoring rule (lower is better).
+    pub cost: f32,
+}
+
+/// Estimates a model from data contaminated by outliers.
+///
+/// Repeatedly draws minimal samples, fits a candidate model to each with
+/// `fit`, and scores the candidate over all data with `residual`. The best
+/// candidate is then refitted to all of its inliers, and the refitted model is
+/// kept unless it scores worse than the candidate. `fit` may return `None`
+/// for degenerate samples (and inlier sets), which are skipped.
+///
+/// Returns `None` if there are fewer data than the sample size or if no sample
+/// produced a model.
+pub fn estimate<
+    Datum,
+    Model,
+    Fit: FnMut(&[&Datum]) -> Option<Model>,
+    Residual: FnMut(&Model, &Datum) -> f32,
+>(
+    data: &[Datum],
+    parameters: &ConsensusParameters,
+    mut fit: Fit,
+    mut residual: Residual,
+) -> Option<ConsensusEstimate<Model>> {
+    if parameters.sample_size == 0 || data.len() < parameters.sample_size {
+        return None;
+    }
+
+    let mut generator = XorShift64::new(parameters.seed);
+    let mut indices: Vec<usize> = (0..data.len()).collect();
+    let mut best: Option<(f32, Model)> = None;
+
+    for _ in 0..parameters.iterations {
+        // Partial Fisher-Yates shuffle; the first sample_size indices are a
+        // uniformly drawn sample without replacement.
+        for i in 0..parameters.sample_size {
+            let j = i + generator.next_below(data.len() - i);
+            indices.swap(i, j);
+        }
+
+        let sample: Vec<&Datum> = indices[..parameters.sample_size]
+            .iter()
+            .map(|&i| &data[i])
+            .collect();
+
+        let Some(candidate) = fit(&sample) else {
+            continue;
+        };
+
+        let (cost, _) = score(data, parameters, &candidate, &mut residual);
+
+        if let Some((best_cost, _)) = &best {
+            if cost >= *best_cost {
+                continue;
+            }
+        }
+        best = Some((cost, candidate));
+    }
+
+    let (best_cost, best_model) = best?;
+    let (_, best_inliers) = score(data, parameters, &best_model, &mut residual);
+
+    let inlier_data: Vec<&Datum> = best_inliers.iter().map(|&i| &data[i]).collect();
+    let refitted = fit(&inlier_data).map(|refitted| {
+        let (cost, inliers) = score(data, parameters, &refitted, &mut residual);
+        (refitted, inliers, cost)
+    });
+    let (model, inliers, cost) = match refitted {
+        Some((refitted, inliers, cost)) if cost <= best_cost => (refitted, inliers, cost),
+        _ => (best_model, best_inliers, best_cost),
+    };
+
+    Some(ConsensusEstimate {
+        model,
+        inliers,
+        cost,
+    })
+}
+
+/// Scores a model over all data, returning its cost and its inliers.
+fn score<Datum, Model, Residual: FnMut(&Model, &Datum) -> f32>(
+    data: &[Datum],
+    parameters: &ConsensusParameters,
+    model: &Model,
+    residual: &mut Residual,
+) -> (f32, Vec<usize>) {
+    let threshold = parameters.inlier_threshold;
+    let mut inliers = Vec::new();
+    let mut cost = 0.0;
+
+    for (i, datum) in data.iter().enumerate() {
+        let r = residual(model, datum).abs();
+        let is_inlier = r <= threshold;
+
+        if is_inlier {
+            inliers.push(i);
+        }
+
+        cost += match parameters.scoring {
+            ConsensusScoring::Ransac => {
+                if is_inlier {
+                    0.0
+                } else {
+                    1.0
+                }
+            }
+            ConsensusScoring::Msac => (r * r).min(threshold * threshold),
+        };
+    }
+
+    (cost, inliers)
+}
+
+/// Minimal xorshift generator, so that sampling needs no external crate and is
+/// reproducible across platforms.
+struct XorShift64 {
+    state: u64,
+}
+
+impl XorShift64 {
+    fn new(seed: u64) -> Self {
+        // The all-zero state is a fixed point of xorshift.
+        XorShift64 {
+            state: if seed == 0 {
+                0x9E37_79B9_7F4A_7C15
+            } else {
+                seed
+            },
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+
+    /// Draws an integer uniformly (up to negligible bias) from [0, bound).
+    fn next_below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
@@ -0,0 +1,202 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+//! Rational number module.
+//!
+//! Exact rational scalars for tests and verification code, where ties and
+//! rounding in f32 would make results depend on evaluation order. Values are
+//! kept in lowest terms with a positive denominator, so structural equality
+//! and hashing agree with numeric equality. Arithmetic is carried out in i128
+//! and panics, as integer arithmetic does, if a result does not fit in i64.
+
+use crate::math::algebra::Scalar;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Rational number `numerator / denominator`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i64,
+    denominator: i64,
+}
+
+impl Rational {
+    /// Builds the rational `numerator / denominator` in lowest terms.
+    ///
+    /// Panics if the denominator is zero.
+    pub fn new(numerator: i64, denominator: i64) -> Self {
+        Rational::reduce(numerator as i128, denominator as i128)
+    }
+
+    /// Builds the rational equal to the given integer.
+    pub fn integer(value: i64) -> Self {
+        Rational::new(value, 1)
+    }
+
+    pub fn numerator(&self) -> i64 {
+        self.numerator
+    }
+
+    /// Returns the denominator, which is always positive.
+    pub fn denominator(&self) -> i64 {
+        self.denominator
+    }
+
+    /// Returns the multiplicative inverse, or `None` for zero.
+    pub fn recip(&self) -> Option<Self> {
+        if self.numerator == 0 {
+            None
+        } else {
+            Some(Rational::new(self.denominator, self.numerator))
+        }
+    }
+
+    /// Returns the nearest f64 to the rational.
+    pub fn to_f64(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+
+    fn reduce(numerator: i128, denominator: i128) -> Self {
+        assert!(denominator != 0, "Rational expects a non-zero denominator.");
+
+        let divisor = gcd(numerator, denominator) * denominator.signum();
+        Rational {
+            numerator: (numerator / divisor)
+                .try_into()
+                .expect("Rational numerator overflowed i64."),
+            denominator: (denominator / divisor)
+                .try_into()
+                .expect("Rational denominator overflowed i64."),
+        }
+    }
+}
+
+/// Returns the (non-negative) greatest common divisor, taking gcd(0, b) = |b|.
+fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.abs()
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Rational::integer(value)
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Rational::reduce(
+            self.numerator as i128 * rhs.denominator as i128
+                + rhs.numerator as i128 * self.denominator as i128,
+            self.denominator as i128 * rhs.denominator as i128,
+        )
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Rational::reduce(-(self.numerator as i128), self.denominator as i128)
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Rational::reduce(
+            self.numerator as i128 * rhs.numerator as i128,
+            self.denominator as i128 * rhs.denominator as i128,
+        )
+    }
+}
+
+/// Panics on division by zero.
+impl Div for Rational {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Rational::reduce(
+            self.numerator as i128 * rhs.denominator as i128,
+            self.denominator as i128 * rhs.numerator as i128,
+        )
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Denominators are positive, so cross-multiplying preserves order.
+        (self.numerator as i128 * other.denominator as i128)
+            .cmp(&(other.numerator as i128 * self.denominator as i128))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Scalar for Rational {
+    fn additive_unit() -> Self {
+        Rational::integer(0)
+    }
+
+    fn multiplicative_unit() -> Self {
+        Rational::integer(1)
+    }
+
+    fn additive_inverse(self) -> Self {
+        -self
+    }
+}
@@ -0,0 +1,608 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+//! Rotations module.
+//!
+//! Conversions between the common representations of 3D orientation: unit
+//! quaternions, rotation matrices, axis-angle pairs and Euler angles. Every
+//! conversion handles its singular cases (zero rotation, half turns, gimbal
+//! lock) explicitly and reports angles wrapped to (-pi, pi].
+
+use crate::math::approx::ApproxEq;
+use crate::math::arrayalgebra::{make_array_matrix, make_array_vector, ArrayMatrix, ArrayVector};
+use std::f32::consts::PI;
+use std::fmt::{Display, Formatter};
+use std::ops::Mul;
+
+/// Tolerance below which an angle, or the cosine of a gimbal-locked Euler
+/// angle, is treated as zero.
+const SINGULARITY_TOLERANCE: f32 = 1e-6;
+
+/// Quaternion w + xi + yj + zk; unit quaternions represent rotations.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Quaternion {
+    pub w: f32,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Quaternion {
+    pub fn new(w: f32, x: f32, y: f32, z: f32) -> Self {
+        Quaternion { w, x, y, z }
+    }
+
+    /// Returns the components as the 4-vector [w, x, y, z].
+    pub fn to_vector(&self) -> ArrayVector<f32, 4> {
+        make_array_vector([self.w, self.x, self.y, self.z])
+    }
+
+    /// Returns the quaternion representing no rotation.
+    pub fn identity() -> Self {
+        Quaternion::new(1.0, 0.0, 0.0, 0.0)
+    }
+
+    pub fn norm(&self) -> f32 {
+        (self.w * self.w + self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+
+    /// Returns the unit quaternion pointing the same way.
+    pub fn normalized(&self) -> Self {
+        let norm = self.norm();
+        Quaternion::new(self.w / norm, self.x / norm, self.y / norm, self.z / norm)
+    }
+
+    /// Returns the conjugate, which for a unit quaternion is the inverse
+    /// rotation.
+    pub fn conjugate(&self) -> Self {
+        Quaternion::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    /// Rotates a vector by this (unit) quaternion.
+    pub fn rotate(&self, vector: ArrayVector<f32, 3>) -> ArrayVector<f32, 3> {
+        let v = Quaternion::new(0.0, vector.get(0), vector.get(1), vector.get(2));
+        let rotated = *self * v * self.conjugate();
+        make_array_vector([rotated.x, rotated.y, rotated.z])
+    }
+}
+
+/// Hamilton product; composing rotations as `a * b` applies `b` first.
+impl Mul for Quaternion {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Quaternion::new(
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+        )
+    }
+}
+
+/// Formats the quaternion as `w + xi + yj + zk`, honouring the precision.
+impl Display for Quaternion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(4);
+        write!(
+            f,
+            "{:.p$} {:+.p$}i {:+.p$}j {:+.p$}k",
+            self.w,
+            self.x,
+            self.y,
+            self.z,
+            p = precision
+        )
+    }
+}
+
+/// Component-wise approximate equality. Note that q and -q represent the
+/// same rotation but do not compare equal; compare rotation matrices instead
+/// when the sign is not canonical.
+impl ApproxEq for Quaternion {
+    type Tolerance = f32;
+
+    fn default_epsilon() -> f32 {
+        f32::default_epsilon()
+    }
+
+    fn default_max_relative() -> f32 {
+        f32::default_max_relative()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.to_vector().abs_diff_eq(&other.to_vector(), epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+        self.to_vector()
+            .relative_eq(&other.to_vector(), epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f32, max_ulps: u32) -> bool {
+        self.to_vector()
+            .ulps_eq(&other.to_vector(), epsilon, max_ulps)
+    }
+}
+
+/// Wraps an angle to the interval (-pi, pi].
+pub fn wrap_angle(angle: f32) -> f32 {
+    let wrapped = (angle + PI).rem_euclid(2.0 * PI) - PI;
+    if wrapped <= -PI {
+        wrapped + 2.0 * PI
+    } else {
+        wrapped
+    }
+}
+
+/// Returns the rotation matrix of a unit quaternion.
+pub fn matrix_from_quaternion(q: &Quaternion) -> ArrayMatrix<f32, 3, 3> {
+    let Quaternion { w, x, y, z } = q.normalized();
+
+    make_array_matrix([
+        [
+            1.0 - 2.0 * (y * y + z * z),
+            2.0 * (x * y - w * z),
+            2.0 * (x * z + w * y),
+        ],
+        [
+            2.0 * (x * y + w * z),
+            1.0 - 2.0 * (x * x + z * z),
+            2.0 * (y * z - w * x),
+        ],
+        [
+            2.0 * (x * z - w * y),
+            2.0 * (y * z + w * x),
+            1.0 - 2.0 * (x * x + y * y),
+        ],
+    ])
+}
+
+/// Returns the unit quaternion of a rotation matrix, with a non-negative
+/// scalar part.
+///
+/// Uses Shepperd's method, which divides by the largest of the candidate
+/// denominators so it stays accurate near half turns.
+pub fn quaternion_from_matrix(m: &ArrayMatrix<f32, 3, 3>) -> Quaternion {
+    let r = |i: usize, j: usize| m.get(i, j);
+    let trace = r(0, 0) + r(1, 1) + r(2, 2);
+
+    let q = if trace > r(0, 0).max(r(1, 1)).max(r(2, 2)) {
+        let s = 2.0 * (1.0 + trace).sqrt();
+        Quaternion::new(
+            0.25 * s,
+            (r(2, 1) - r(1, 2)) / s,
+            (r(0, 2) - r(2, 0)) / s,
+            (r(1, 0) - r(0, 1)) / s,
+        )
+    } else if r(0, 0) >= r(1, 1) && r(0, 0) >= r(2, 2) {
+        let s = 2.0 * (1.0 + r(0, 0) - r(1, 1) - r(2, 2)).sqrt();
+        Quaternion::new(
+            (r(2, 1) - r(1, 2)) / s,
+            0.25 * s,
+            (r(0, 1) + r(1, 0)) / s,
+            (r(0, 2) + r(2, 0)) / s,
+        )
+    } else if r(1, 1) >= r(2, 2) {
+        let s = 2.0 * (1.0 - r(0, 0) + r(1, 1) - r(2, 2)).sqrt();
+        Quaternion::new(
+            (r(0, 2) - r(2, 0)) / s,
+            (r(0, 1) + r(1, 0)) / s,
+            0.25 * s,
+            (r(1, 2) + r(2, 1)) / s,
+        )
+    } else {
+        let s = 2.0 * (1.0 - r(0, 0) - r(1, 1) + r(2, 2)).sqrt();
+        Quaternion::new(
+            (r(1, 0) - r(0, 1)) / s,
+            (r(0, 2) + r(2, 0)) / s,
+            (r(1, 2) + r(2, 1)) / s,
+            0.25 * s,
+        )
+    };
+
+    let q = q.normalized();
+    if q.w < 0.0 {
+        Quaternion::new(-q.w, -q.x, -q.y, -q.z)
+    } else {
+        q
+    }
+}
+
+/// Returns the unit quaternion rotating by `angle` radians about `axis`. The
+/// axis need not be normalized; a zero axis yields the identity.
+pub fn quaternion_from_axis_angle(axis: ArrayVector<f32, 3>, angle: f32) -> Quaternion {
+    let norm = (axis * axis).sqrt();
+    if norm < SINGULARITY_TOLERANCE {
+        return Quaternion::identity();
+    }
+
+    let (sin, cos) = (angle / 2.0).sin_cos();
+    let scale = sin / norm;
+    Quaternion::new(
+        cos,
+        axis.get(0) * scale,
+        axis.get(1) * scale,
+        axis.get(2) * scale,
+    )
+}
+
+/// Returns the unit axis and angle, in [0, pi], of a unit quaternion. The
+/// identity rotation has no defined axis and reports the x axis.
+pub fn axis_angle_from_quaternion(q: &Quaternion) -> (ArrayVector<f32, 3>, f32) {
+    let q = q.normalized();
+    // q and -q are the same rotation; pick the one with the shorter angle.
+    let q = if q.w < 0.0 {
+        Quaternion::new(-q.w, -q.x, -q.y, -q.z)
+    } else {
+        q
+    };
+
+    let sin = (q.x * q.x + q.y * q.y + q.z * q.z).sqrt();
+    let angle = 2.0 * sin.atan2(q.w);
+
+    if sin < SINGULARITY_TOLERANCE {
+        return (make_array_vector([1.0, 0.0, 0.0]), 0.0);
+    }
+
+    (make_array_vector([q.x / sin, q.y / sin, q.z / sin]), angle)
+}
+
+/// Returns the rotation matrix rotating by `angle` radians about `axis`.
+pub fn matrix_from_axis_angle(axis: ArrayVector<f32, 3>, angle: f32) -> ArrayMatrix<f32, 3, 3> {
+    matrix_from_quaternion(&quaternion_from_axis_angle(axis, angle))
+}
+
+/// Returns the unit axis and angle, in [0, pi], of a rotation matrix.
+pub fn axis_angle_from_matrix(m: &ArrayMatrix<f32, 3, 3>) -> (ArrayVector<f32, 3>, f32) {
+    axis_angle_from_quaternion(&quaternion_from_matrix(m))
+}
+
+/// Axis-angle rotation: a rotation by `angle` radians about the unit vector
+/// `axis`.
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AxisAngle {
+    axis: ArrayVector<f32, 3>,
+    angle: f32,
+}
+
+impl AxisAngle {
+    /// Builds a rotation about `axis`, which need not be of unit length. A
+    /// (near) zero axis gives the identity rotation.
+    pub fn new(axis: ArrayVector<f32, 3>, angle: f32) -> Self {
+        let norm = (axis * axis).sqrt();
+        if norm < SINGULARITY_TOLERANCE {
+            return AxisAngle::identity();
+        }
+
+        AxisAngle {
+            axis: make_array_vector([axis.get(0) / norm, axis.get(1) / norm, axis.get(2) / norm]),
+            angle,
+        }
+    }
+
+    /// Returns the rotation representing no rotation, about the x axis.
+    pub fn identity() -> Self {
+        AxisAngle {
+            axis: make_array_vector([1.0, 0.0, 0.0]),
+            angle: 0.0,
+        }
+    }
+
+    /// Builds a rotation from a rotation vector, whose direction is the axis
+    /// and whose length is the angle.
+    pub fn from_rotation_vector(vector: ArrayVector<f32, 3>) -> Self {
+        AxisAngle::new(vector, (vector * vector).sqrt())
+    }
+
+    pub fn axis(&self) -> ArrayVector<f32, 3> {
+        self.axis
+    }
+
+    pub fn angle(&self) -> f32 {
+        self.angle
+    }
+
+    /// Returns the rotation vector, `angle * axis`.
+    pub fn rotation_vector(&self) -> ArrayVector<f32, 3> {
+        make_array_vector([
+            self.axis.get(0) * self.angle,
+            self.axis.get(1) * self.angle,
+            self.axis.get(2) * self.angle,
+        ])
+    }
+
+    /// Returns the unit quaternion of the rotation.
+    pub fn to_quaternion(&self) -> Quaternion {
+        quaternion_from_axis_angle(self.axis, self.angle)
+    }
+
+    /// Builds the rotation of a unit quaternion, with angle in [0, pi].
+    pub fn from_quaternion(q: &Quaternion) -> Self {
+        let (axis, angle) = axis_angle_from_quaternion(q);
+        AxisAngle { axis, angle }
+    }
+
+    /// Returns the rotation matrix of the rotation.
+    pub fn to_matrix(&self) -> ArrayMatrix<f32, 3, 3> {
+        matrix_from_axis_angle(self.axis, self.angle)
+    }
+
+    /// Builds the rotation of a rotation matrix, with angle in [0, pi].
+    pub fn from_matrix(m: &ArrayMatrix<f32, 3, 3>) -> Self {
+        AxisAngle::from_quaternion(&quaternion_from_matrix(m))
+    }
+
+    /// Rotates a vector.
+    pub fn rotate(&self, vector: ArrayVector<f32, 3>) -> ArrayVector<f32, 3> {
+        self.to_quaternion().rotate(vector)
+    }
+}
+
+impl From<AxisAngle> for Quaternion {
+    fn from(rotation: AxisAngle) -> Self {
+        rotation.to_quaternion()
+    }
+}
+
+impl From<Quaternion> for AxisAngle {
+    fn from(q: Quaternion) -> Self {
+        AxisAngle::from_quaternion(&q)
+    }
+}
+
+/// Returns the rotation vector of a unit quaternion, i.e. its logarithm map.
+///
+/// Near the identity the axis is ill-conditioned, but the rotation vector is
+/// not: this uses the small-angle limit 2 v / w of 2 atan(|v| / w) v / |v|.
+pub fn rotation_vector_from_quaternion(q: &Quaternion) -> ArrayVector<f32, 3> {
+    let q = q.normalized();
+    let q = if q.w < 0.0 {
+        Quaternion::new(-q.w, -q.x, -q.y, -q.z)
+    } else {
+        q
+    };
+
+    let sin = (q.x * q.x + q.y * q.y + q.z * q.z).sqrt();
+    let scale = if sin < SINGULARITY_TOLERANCE.sqrt() {
+        // 2 atan(s / w) / s = 2 / w (1 - s^2 / (3 w^2) + ...).
+        2.0 / q.w * (1.0 - sin * sin / (3.0 * q.w * q.w))
+    } else {
+        2.0 * sin.atan2(q.w) / sin
+    };
+    make_array_vector([q.x * scale, q.y * scale, q.z * scale])
+}
+
+/// Returns the unit quaternion of a rotation vector, i.e. its exponential
+/// map. Remains accurate for vectors of arbitrarily small length.
+pub fn quaternion_from_rotation_vector(vector: ArrayVector<f32, 3>) -> Quaternion {
+    let angle = (vector * vector).sqrt();
+    let (sin, cos) = (angle / 2.0).sin_cos();
+    let scale = if angle < SINGULARITY_TOLERANCE.sqrt() {
+        // sin(t / 2) / t = 1 / 2 - t^2 / 48 + ...
+        0.5 - angle * angle / 48.0
+    } else {
+        sin / angle
+    };
+    Quaternion::new(
+        cos,
+        vector.get(0) * scale,
+        vector.get(1) * scale,
+        vector.get(2) * scale,
+    )
+}
+
+/// Spherical linear interpolation between unit quaternions.
+///
+/// Returns `a` at t = 0 and `b` at t = 1, rotating at constant angular
+/// velocity about a fixed axis in between. Always takes the shorter of the two
+/// arcs between `a` and `b`.
+pub fn slerp(a: &Quaternion, b: &Quaternion, t: f32) -> Quaternion {
+    let (a, b) = (a.normalized(), b.normalized());
+    let mut cos = a.w * b.w + a.x * b.x + a.y * b.y + a.z * b.z;
+    let b = if cos < 0.0 {
+        cos = -cos;
+        Quaternion::new(-b.w, -b.x, -b.y, -b.z)
+    } else {
+        b
+    };
+
+    let (ka, kb) = if cos > 1.0 - SINGULARITY_TOLERANCE {
+        // Nearly parallel; the linear blend is accurate and well-conditioned.
+        (1.0 - t, t)
+    } else {
+        let theta = cos.acos();
+        let sin = theta.sin();
+        (((1.0 - t) * theta).sin() / sin, (t * theta).sin() / sin)
+    };
+
+    Quaternion::new(
+        ka * a.w + kb * b.w,
+        ka * a.x + kb * b.x,
+        ka * a.y + kb * b.y,
+        ka * a.z + kb * b.z,
+    )
+    .normalized()
+}
+
+/// Spherical linear interpolation between rotation matrices; see `slerp`.
+pub fn slerp_matrix(
+    a: &ArrayMatrix<f32, 3, 3>,
+    b: &ArrayMatrix<f32, 3, 3>,
+    t: f32,
+) -> ArrayMatrix<f32, 3, 3> {
+    matrix_from_quaternion(&slerp(
+        &quaternion_from_matrix(a),
+        &quaternion_from_matrix(b),
+        t,
+    ))
+}
+
+/// Euler angle sequence.
+///
+/// Names the axes of three successive intrinsic rotations: angles (a, b, c)
+/// in sequence `ZYX` describe the rotation Rz(a) Ry(b) Rx(c), i.e. yaw, then
+/// pitch about the new y axis, then roll about the newest x axis. The same
+/// angles read in reverse describe the extrinsic (fixed-axis) sequence.
+///
+/// All twelve standard sequences are supported: the six Tait-Bryan sequences,
+/// which rotate about three distinct axes, and the six proper Euler sequences
+/// (e.g. `ZYZ`), which rotate about the first axis again last.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EulerSequence {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+impl EulerSequence {
+    /// All supported sequences, Tait-Bryan first.
+    pub const ALL: [EulerSequence; 12] = [
+        EulerSequence::XYZ,
+        EulerSequence::XZY,
+        EulerSequence::YXZ,
+        EulerSequence::YZX,
+        EulerSequence::ZXY,
+        EulerSequence::ZYX,
+        EulerSequence::XYX,
+        EulerSequence::XZX,
+        EulerSequence::YXY,
+        EulerSequence::YZY,
+        EulerSequence::ZXZ,
+        EulerSequence::ZYZ,
+    ];
+
+    /// Returns the indices of the three rotation axes, in order.
+    fn axes(&self) -> (usize, usize, usize) {
+        match self {
+            EulerSequence::XYZ => (0, 1, 2),
+            EulerSequence::XZY => (0, 2, 1),
+            EulerSequence::YXZ => (1, 0, 2),
+            EulerSequence::YZX => (1, 2, 0),
+            EulerSequence::ZXY => (2, 0, 1),
+            EulerSequence::ZYX => (2, 1, 0),
+            EulerSequence::XYX => (0, 1, 0),
+            EulerSequence::XZX => (0, 2, 0),
+            EulerSequence::YXY => (1, 0, 1),
+            EulerSequence::YZY => (1, 2, 1),
+            EulerSequence::ZXZ => (2, 0, 2),
+            EulerSequence::ZYZ => (2, 1, 2),
+        }
+    }
+
+    /// Checks if the sequence is a proper Euler sequence, i.e. if its first
+    /// and last axes coincide.
+    pub fn is_proper(&self) -> bool {
+        let (i, _, k) = self.axes();
+        i == k
+    }
+
+    /// Returns 1 if the first two axes are a cyclic step of x, y, z and -1
+    /// otherwise.
+    fn parity(&self) -> f32 {
+        let (i, j, _) = self.axes();
+        if (i + 1) % 3 == j {
+            1.0
+        } else {
+            -1.0
+        }
+    }
+}
+
+/// Returns the rotation matrix of Euler angles in the given sequence.
+pub fn matrix_from_euler(sequence: EulerSequence, angles: [f32; 3]) -> ArrayMatrix<f32, 3, 3> {
+    let (i, j, k) = sequence.axes();
+    let basis = |axis: usize| {
+        let mut e = [0.0; 3];
+        e[axis] = 1.0;
+        make_array_vector(e)
+    };
+
+    matrix_from_axis_angle(basis(i), angles[0])
+        * matrix_from_axis_angle(basis(j), angles[1])
+        * matrix_from_axis_angle(basis(k), angles[2])
+}
+
+/// Returns the Euler angles in the given sequence of a rotation matrix.
+///
+/// The middle angle lies in [-pi/2, pi/2] for Tait-Bryan sequences and in
+/// [0, pi] for proper Euler sequences; the others lie in (-pi, pi]. At gimbal
+/// lock (middle angle of +-pi/2, or of 0 or pi, respectively) only the sum or
+/// difference of the outer angles is determined; the last angle is then
+/// reported as zero.
+pub fn euler_from_matrix(sequence: EulerSequence, m: &ArrayMatrix<f32, 3, 3>) -> [f32; 3] {
+    let (i, j, _) = sequence.axes();
+    // The axis not among the first two, whichever sequence this is.
+    let k = 3 - i - j;
+    let s = sequence.parity();
+    let r = |row: usize, column: usize| m.get(row, column);
+
+    let (b, locked) = if sequence.is_proper() {
+        let b = r(i, i).clamp(-1.0, 1.0).acos();
+        (b, b.sin() < SINGULARITY_TOLERANCE.sqrt())
+    } else {
+        let b = (s * r(i, k)).clamp(-1.0, 1.0).asin();
+        (b, b.cos() < SINGULARITY_TOLERANCE.sqrt())
+    };
+
+    if locked {
+        let a = (s * r(k, j)).atan2(r(j, j));
+        return [wrap_angle(a), b, 0.0];
+    }
+
+    let (a, c) = if sequence.is_proper() {
+        (r(j, i).atan2(-s * r(k, i)), r(i, j).atan2(s * r(i, k)))
+    } else {
+        ((-s * r(j, k)).atan2(r(k, k)), (-s * r(i, j)).atan2(r(i, i)))
+    };
+    [wrap_angle(a), b, wrap_angle(c)]
+}
+
+/// Returns the unit quaternion of Euler angles in the given sequence.
+pub fn quaternion_from_euler(sequence: EulerSequence, angles: [f32; 3]) -> Quaternion {
+    quaternion_from_matrix(&matrix_from_euler(sequence, angles))
+}
+
+/// Returns the Euler angles in the given sequence of a unit quaternion, as
+/// `euler_from_matrix` does.
+pub fn euler_from_quaternion(sequence: EulerSequence, q: &Quaternion) -> [f32; 3] {
+    euler_from_matrix(sequence, &matrix_from_quaternion(q))
+}
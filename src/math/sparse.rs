@@ -0,0 +1,281 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+//! Sparse linear algebra module.
+//!
+//! Provides a compressed sparse row (CSR) matrix over any `Real` scalar and
+//! a conjugate-gradient solver for the large, sparse, symmetric positive
+//! definite systems that arise in pose-graph optimization and estimation.
+//! Dense vectors are plain slices and `Vec`s, since their sizes are only
+//! known at runtime.
+
+use crate::math::algebra::{Real, Scalar};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Sparse Linear Algebra Failures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SparseFailure {
+    /// Reported when a triplet lies outside the matrix.
+    IndexOutOfBounds(usize, usize),
+
+    /// Reported when operands have incompatible dimensions.
+    DimensionMismatch,
+
+    /// Reported when an iterative solver does not reach its tolerance within
+    /// its iteration limit.
+    NotConverged,
+}
+
+impl Display for SparseFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SparseFailure::IndexOutOfBounds(row, column) => {
+                write!(f, "Entry ({row}, {column}) lies outside the matrix.")
+            }
+            SparseFailure::DimensionMismatch => {
+                write!(f, "Operands have incompatible dimensions.")
+            }
+            SparseFailure::NotConverged => {
+                write!(f, "Solver did not converge within its iteration limit.")
+            }
+        }
+    }
+}
+
+impl Error for SparseFailure {}
+
+/// Compressed Sparse Row (CSR) matrix.
+///
+/// Stores the non-zero entries of every row as a contiguous slice of a single
+/// array, sorted by column and delimited by offsets.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsrMatrix<F: Scalar> {
+    rows: usize,
+    columns: usize,
+    row_offsets: Vec<usize>,
+    column_indices: Vec<usize>,
+    values: Vec<F>,
+}
+
+impl<F: Scalar> CsrMatrix<F> {
+    /// Builds a matrix from (row, column, value) triplets in any order.
+    /// Duplicate entries are summed, as when assembling a system from many
+    /// overlapping contributions.
+    pub fn from_triplets(
+        rows: usize,
+        columns: usize,
+        triplets: &[(usize, usize, F)],
+    ) -> Result<Self, SparseFailure> {
+        if let Some((row, column, _)) = triplets
+            .iter()
+            .find(|(r, c, _)| *r >= rows || *c >= columns)
+        {
+            return Err(SparseFailure::IndexOutOfBounds(*row, *column));
+        }
+
+        let mut sorted = triplets.to_vec();
+        sorted.sort_by_key(|(row, column, _)| (*row, *column));
+
+        let mut row_offsets = vec![0; rows + 1];
+        let mut column_indices: Vec<usize> = Vec::with_capacity(sorted.len());
+        let mut values: Vec<F> = Vec::with_capacity(sorted.len());
+        let mut last: Option<(usize, usize)> = None;
+
+        for (row, column, value) in sorted {
+            if last == Some((row, column)) {
+                let previous = values.last_mut().unwrap();
+                *previous = *previous + value;
+                continue;
+            }
+            last = Some((row, column));
+            row_offsets[row + 1] += 1;
+            column_indices.push(column);
+            values.push(value);
+        }
+        for row in 0..rows {
+            row_offsets[row + 1] += row_offsets[row];
+        }
+
+        Ok(CsrMatrix {
+            rows,
+            columns,
+            row_offsets,
+            column_indices,
+            values,
+        })
+    }
+
+    /// Returns the n x n identity matrix.
+    pub fn identity(n: usize) -> Self {
+        CsrMatrix {
+            rows: n,
+            columns: n,
+            row_offsets: (0..=n).collect(),
+            column_indices: (0..n).collect(),
+            values: vec![F::multiplicative_unit(); n],
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Returns the number of stored entries.
+    pub fn nonzero_count(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the entry at the given row and column, which is zero if it is
+    /// not stored.
+    pub fn get(&self, row: usize, column: usize) -> F {
+        self.row(row)
+            .find(|(c, _)| *c == column)
+            .map(|(_, value)| value)
+            .unwrap_or(F::additive_unit())
+    }
+
+    /// Iterates over the stored (column, value) entries of a row, in column
+    /// order.
+    pub fn row(&self, row: usize) -> impl Iterator<Item = (usize, F)> + '_ {
+        let range = self.row_offsets[row]..self.row_offsets[row + 1];
+        self.column_indices[range.clone()]
+            .iter()
+            .copied()
+            .zip(self.values[range].iter().copied())
+    }
+
+    /// Returns the matrix-vector product A x.
+    pub fn mul_vector(&self, x: &[F]) -> Result<Vec<F>, SparseFailure> {
+        if x.len() != self.columns {
+            return Err(SparseFailure::DimensionMismatch);
+        }
+
+        Ok((0..self.rows)
+            .map(|row| {
+                self.row(row)
+                    .fold(F::additive_unit(), |sum, (column, value)| {
+                        sum + value * x[column]
+                    })
+            })
+            .collect())
+    }
+
+    /// Returns the transposed matrix.
+    pub fn transpose(&self) -> Self {
+        let triplets: Vec<(usize, usize, F)> = (0..self.rows)
+            .flat_map(|row| {
+                self.row(row)
+                    .map(move |(column, value)| (column, row, value))
+            })
+            .collect();
+
+        CsrMatrix::from_triplets(self.columns, self.rows, &triplets)
+            .expect("Transposed entries lie inside the transposed matrix.")
+    }
+}
+
+/// Result of an iterative solve.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IterativeSolution<F> {
+    pub solution: Vec<F>,
+    pub iterations: usize,
+    pub residual_norm: F,
+}
+
+fn dot<F: Scalar>(a: &[F], b: &[F]) -> F {
+    a.iter()
+        .zip(b.iter())
+        .fold(F::additive_unit(), |sum, (x, y)| sum + *x * *y)
+}
+
+/// Solves A x = b for symmetric positive definite A by the conjugate
+/// gradient method, starting from zero.
+///
+/// Stops once the residual norm |b - A x| is at most `tolerance` times |b|,
+/// failing with `NotConverged` after `max_iterations` iterations. In exact
+/// arithmetic at most n iterations are needed for an n x n system.
+pub fn conjugate_gradient<F: Real>(
+    a: &CsrMatrix<F>,
+    b: &[F],
+    tolerance: F,
+    max_iterations: usize,
+) -> Result<IterativeSolution<F>, SparseFailure> {
+    if a.rows != a.columns || b.len() != a.rows {
+        return Err(SparseFailure::DimensionMismatch);
+    }
+
+    let zero = F::additive_unit();
+    let threshold = tolerance * dot(b, b).sqrt();
+    let mut x = vec![zero; b.len()];
+    let mut residual = b.to_vec();
+    let mut direction = residual.clone();
+    let mut residual_squared = dot(&residual, &residual);
+
+    for iteration in 0..=max_iterations {
+        if residual_squared.sqrt() <= threshold {
+            return Ok(IterativeSolution {
+                solution: x,
+                iterations: iteration,
+                residual_norm: residual_squared.sqrt(),
+            });
+        }
+        if iteration == max_iterations {
+            break;
+        }
+
+        let a_direction = a.mul_vector(&direction)?;
+        let curvature = dot(&direction, &a_direction);
+        if curvature <= zero {
+            // A is not positive definite along this direction.
+            break;
+        }
+        let step = residual_squared / curvature;
+
+        for (xi, di) in x.iter_mut().zip(direction.iter()) {
+            *xi = *xi + step * *di;
+        }
+        for (ri, adi) in residual.iter_mut().zip(a_direction.iter()) {
+            *ri = *ri - step * *adi;
+        }
+
+        let next_squared = dot(&residual, &residual);
+        let beta = next_squared / residual_squared;
+        residual_squared = next_squared;
+        for (di, ri) in direction.iter_mut().zip(residual.iter()) {
+            *di = *ri + beta * *di;
+        }
+    }
+
+    Err(SparseFailure::NotConverged)
+}
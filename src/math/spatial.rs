@@ -0,0 +1,497 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+//! Spatial module.
+//!
+//! Rigid-body transformations of 3D space, i.e. elements of the special
+//! Euclidean group SE(3), and the spatial (6D) velocity and force vectors they
+//! act on, over f32.
+
+use crate::math::algebra::CrossProduct;
+use crate::math::approx::ApproxEq;
+use crate::math::arrayalgebra::{make_array_matrix, make_array_vector, ArrayMatrix, ArrayVector};
+use crate::math::rotations::{
+    matrix_from_axis_angle, matrix_from_quaternion, quaternion_from_matrix,
+    quaternion_from_rotation_vector, rotation_vector_from_quaternion, Quaternion,
+};
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Mul, Neg};
+
+/// Angle below which the exponential and logarithm maps use their series
+/// expansions.
+const SMALL_ANGLE: f32 = 1e-3;
+
+/// Largest entry-wise deviation from orthonormality accepted for the rotation
+/// of a transformation read from outside the crate.
+const ROTATION_TOLERANCE: f32 = 1e-4;
+
+/// Returns whether the matrix is orthonormal with unit determinant, up to
+/// `ROTATION_TOLERANCE`.
+fn is_rotation(rotation: &ArrayMatrix<f32, 3, 3>) -> bool {
+    (rotation.transpose() * *rotation).abs_diff_eq(&ArrayMatrix::identity(), ROTATION_TOLERANCE)
+        && (rotation.determinant() - 1.0).abs() <= ROTATION_TOLERANCE
+}
+
+/// SE(3) rigid transformation.
+///
+/// Rotates and then translates: a point p maps to R p + t. Composition
+/// `a * b` applies `b` first, so chaining poses reads left to right from the
+/// outermost frame, e.g. `world_from_base * base_from_tool`.
+///
+/// With the `serde` feature enabled, deserialization rejects rotations that
+/// are not orthonormal with unit determinant.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "SE3Repr")
+)]
+pub struct SE3 {
+    rotation: ArrayMatrix<f32, 3, 3>,
+    translation: ArrayVector<f32, 3>,
+}
+
+impl SE3 {
+    /// Builds a transformation from a rotation matrix and a translation. The
+    /// matrix is assumed to be orthonormal with unit determinant.
+    pub fn new(rotation: ArrayMatrix<f32, 3, 3>, translation: ArrayVector<f32, 3>) -> Self {
+        SE3 {
+            rotation,
+            translation,
+        }
+    }
+
+    /// Returns the transformation with its rotation re-orthonormalized, which
+    /// removes the drift accumulated over many compositions. The first column
+    /// of the rotation (the x axis) keeps its direction.
+    pub fn orthonormalized(&self) -> Self {
+        let rotation = self
+            .rotation
+            .orthonormalize()
+            .expect("Rotation of a rigid transformation has linearly independent columns.");
+        SE3::new(rotation, self.translation)
+    }
+
+    /// Builds a transformation from a unit quaternion and a translation.
+    pub fn from_quaternion(rotation: &Quaternion, translation: ArrayVector<f32, 3>) -> Self {
+        SE3::new(matrix_from_quaternion(rotation), translation)
+    }
+
+    /// Builds a transformation that rotates by `angle` radians about `axis`
+    /// and places the origin at `origin`.
+    pub fn from_axis_angle(
+        axis: ArrayVector<f32, 3>,
+        angle: f32,
+        origin: ArrayVector<f32, 3>,
+    ) -> Self {
+        SE3::new(matrix_from_axis_angle(axis, angle), origin)
+    }
+
+    /// Builds the transformation of a link from (standard, distal)
+    /// Denavit-Hartenberg parameters: Rz(theta) Tz(d) Tx(a) Rx(alpha).
+    pub fn from_dh(a: f32, alpha: f32, d: f32, theta: f32) -> Self {
+        let (st, ct) = theta.sin_cos();
+        let (sa, ca) = alpha.sin_cos();
+
+        SE3::new(
+            make_array_matrix([
+                [ct, -st * ca, st * sa],
+                [st, ct * ca, -ct * sa],
+                [0.0, sa, ca],
+            ]),
+            make_array_vector([a * ct, a * st, d]),
+        )
+    }
+
+    /// Builds the transformation of a link from modified (proximal, Craig's)
+    /// Denavit-Hartenberg parameters: Rx(alpha) Tx(a) Rz(theta) Tz(d).
+    pub fn from_modified_dh(a: f32, alpha: f32, d: f32, theta: f32) -> Self {
+        let (st, ct) = theta.sin_cos();
+        let (sa, ca) = alpha.sin_cos();
+
+        SE3::new(
+            make_array_matrix([
+                [ct, -st, 0.0],
+                [st * ca, ct * ca, -sa],
+                [st * sa, ct * sa, ca],
+            ]),
+            make_array_vector([a, -sa * d, ca * d]),
+        )
+    }
+
+    /// Returns the transformation that moves nothing.
+    pub fn identity() -> Self {
+        SE3::new(ArrayMatrix::identity(), make_array_vector([0.0; 3]))
+    }
+
+    pub fn rotation(&self) -> ArrayMatrix<f32, 3, 3> {
+        self.rotation
+    }
+
+    pub fn translation(&self) -> ArrayVector<f32, 3> {
+        self.translation
+    }
+
+    /// Returns the rotation as a unit quaternion.
+    pub fn quaternion(&self) -> Quaternion {
+        quaternion_from_matrix(&self.rotation)
+    }
+
+    /// Returns the inverse transformation, computed in closed form as
+    /// (R^T, -R^T t).
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.transpose();
+        SE3::new(rotation, -(rotation * self.translation))
+    }
+
+    /// Maps a point, applying both rotation and translation.
+    pub fn transform_point(&self, point: ArrayVector<f32, 3>) -> ArrayVector<f32, 3> {
+        self.rotation * point + self.translation
+    }
+
+    /// Maps a free vector (direction or displacement), which is only rotated.
+    pub fn transform_vector(&self, vector: ArrayVector<f32, 3>) -> ArrayVector<f32, 3> {
+        self.rotation * vector
+    }
+
+    /// Returns the 4x4 homogeneous matrix of the transformation.
+    pub fn to_matrix(&self) -> ArrayMatrix<f32, 4, 4> {
+        let (r, t) = (&self.rotation, &self.translation);
+        make_array_matrix([
+            [r.get(0, 0), r.get(0, 1), r.get(0, 2), t.get(0)],
+            [r.get(1, 0), r.get(1, 1), r.get(1, 2), t.get(1)],
+            [r.get(2, 0), r.get(2, 1), r.get(2, 2), t.get(2)],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Builds a transformation from a 4x4 homogeneous matrix, ignoring its
+    /// bottom row.
+    pub fn from_matrix(m: &ArrayMatrix<f32, 4, 4>) -> Self {
+        let row = |i: usize| [m.get(i, 0), m.get(i, 1), m.get(i, 2)];
+        SE3::new(
+            make_array_matrix([row(0), row(1), row(2)]),
+            make_array_vector([m.get(0, 3), m.get(1, 3), m.get(2, 3)]),
+        )
+    }
+
+    /// Builds a transformation from a 4x4 homogeneous matrix, or returns
+    /// `None` if its bottom row is not [0, 0, 0, 1] or its rotation is not
+    /// orthonormal with unit determinant.
+    pub fn try_from_matrix(m: &ArrayMatrix<f32, 4, 4>) -> Option<Self> {
+        let bottom = make_array_vector([m.get(3, 0), m.get(3, 1), m.get(3, 2), m.get(3, 3)]);
+        if !bottom.abs_diff_eq(&make_array_vector([0.0, 0.0, 0.0, 1.0]), ROTATION_TOLERANCE) {
+            return None;
+        }
+
+        let pose = SE3::from_matrix(m);
+        is_rotation(&pose.rotation).then_some(pose)
+    }
+
+    /// Returns the 6x6 adjoint matrix, which maps twists written as
+    /// [angular; linear] from the transformation's source frame to its target
+    /// frame, as `transform_twist` does.
+    pub fn adjoint(&self) -> ArrayMatrix<f32, 6, 6> {
+        let r = &self.rotation;
+        let pr = skew(self.translation) * self.rotation;
+        let mut rows = [[0.0; 6]; 6];
+
+        for (i, row) in rows.iter_mut().enumerate() {
+            for j in 0..3 {
+                if i < 3 {
+                    row[j] = r.get(i, j);
+                } else {
+                    row[j] = pr.get(i - 3, j);
+                    row[j + 3] = r.get(i - 3, j);
+                }
+            }
+        }
+
+        make_array_matrix(rows)
+    }
+
+    /// Re-expresses a twist given in the transformation's source frame in its
+    /// target frame.
+    pub fn transform_twist(&self, twist: &Twist) -> Twist {
+        let angular = self.rotation * twist.angular;
+        Twist::new(
+            angular,
+            self.rotation * twist.linear + self.translation.cross(angular),
+        )
+    }
+
+    /// Re-expresses a wrench given in the transformation's source frame in its
+    /// target frame; this is the dual of `transform_twist`, so the power of a
+    /// wrench on a twist is the same in either frame.
+    pub fn transform_wrench(&self, wrench: &Wrench) -> Wrench {
+        let force = self.rotation * wrench.force;
+        Wrench::new(
+            self.rotation * wrench.torque + self.translation.cross(force),
+            force,
+        )
+    }
+
+    /// Returns the transformation reached by following `twist` for unit time,
+    /// i.e. the exponential map of se(3). The twist is [angular; linear],
+    /// written in the frame being moved.
+    pub fn exp(twist: &Twist) -> Self {
+        let (w, v) = (twist.angular, twist.linear);
+        let angle = (w * w).sqrt();
+        // V = I + b [w] + c [w]^2 maps the linear velocity to the translation.
+        let (b, c) = if angle < SMALL_ANGLE {
+            (
+                0.5 - angle * angle / 24.0,
+                1.0 / 6.0 - angle * angle / 120.0,
+            )
+        } else {
+            let (sin, cos) = angle.sin_cos();
+            (
+                (1.0 - cos) / (angle * angle),
+                (angle - sin) / (angle * angle * angle),
+            )
+        };
+
+        let wv = w.cross(v);
+        SE3::from_quaternion(
+            &quaternion_from_rotation_vector(w),
+            v + wv * b + w.cross(wv) * c,
+        )
+    }
+
+    /// Returns the twist whose exponential is this transformation, with an
+    /// angular part of length at most pi; the inverse of `exp`.
+    pub fn log(&self) -> Twist {
+        let w = rotation_vector_from_quaternion(&self.quaternion());
+        let p = self.translation;
+        let angle = (w * w).sqrt();
+        // V^-1 = I - [w] / 2 + d [w]^2.
+        let d = if angle < SMALL_ANGLE {
+            1.0 / 12.0 + angle * angle / 720.0
+        } else {
+            let (sin, cos) = angle.sin_cos();
+            (1.0 - angle * sin / (2.0 * (1.0 - cos))) / (angle * angle)
+        };
+
+        let wp = w.cross(p);
+        Twist::new(w, p + wp * -0.5 + w.cross(wp) * d)
+    }
+
+    /// Screw interpolation: returns `self` at t = 0 and `other` at t = 1,
+    /// moving along the constant-twist screw motion between them.
+    pub fn interpolate(&self, other: &SE3, t: f32) -> SE3 {
+        let displacement = (self.inverse() * *other).log();
+        *self * SE3::exp(&(displacement * t))
+    }
+}
+
+/// Returns the skew-symmetric matrix [v] such that [v] w = v x w.
+fn skew(v: ArrayVector<f32, 3>) -> ArrayMatrix<f32, 3, 3> {
+    let (x, y, z) = (v.get(0), v.get(1), v.get(2));
+    make_array_matrix([[0.0, -z, y], [z, 0.0, -x], [-y, x, 0.0]])
+}
+
+/// Twist.
+///
+/// Spatial velocity of a rigid body: its angular velocity and the linear
+/// velocity of the point at the origin of the frame it is written in.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Twist {
+    pub angular: ArrayVector<f32, 3>,
+    pub linear: ArrayVector<f32, 3>,
+}
+
+impl Twist {
+    pub fn new(angular: ArrayVector<f32, 3>, linear: ArrayVector<f32, 3>) -> Self {
+        Twist { angular, linear }
+    }
+
+    pub fn zero() -> Self {
+        Twist::new(make_array_vector([0.0; 3]), make_array_vector([0.0; 3]))
+    }
+
+    /// Returns the twist as the 6-vector [angular; linear].
+    pub fn to_vector(&self) -> ArrayVector<f32, 6> {
+        let (w, v) = (&self.angular, &self.linear);
+        make_array_vector([w.get(0), w.get(1), w.get(2), v.get(0), v.get(1), v.get(2)])
+    }
+}
+
+impl Add for Twist {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Twist::new(self.angular + rhs.angular, self.linear + rhs.linear)
+    }
+}
+
+impl Neg for Twist {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Twist::new(-self.angular, -self.linear)
+    }
+}
+
+impl Mul<f32> for Twist {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Twist::new(self.angular * rhs, self.linear * rhs)
+    }
+}
+
+/// Wrench.
+///
+/// Spatial force on a rigid body: the torque about the origin of the frame it
+/// is written in, and the force.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Wrench {
+    pub torque: ArrayVector<f32, 3>,
+    pub force: ArrayVector<f32, 3>,
+}
+
+impl Wrench {
+    pub fn new(torque: ArrayVector<f32, 3>, force: ArrayVector<f32, 3>) -> Self {
+        Wrench { torque, force }
+    }
+
+    pub fn zero() -> Self {
+        Wrench::new(make_array_vector([0.0; 3]), make_array_vector([0.0; 3]))
+    }
+
+    /// Returns the wrench as the 6-vector [torque; force].
+    pub fn to_vector(&self) -> ArrayVector<f32, 6> {
+        let (t, f) = (&self.torque, &self.force);
+        make_array_vector([t.get(0), t.get(1), t.get(2), f.get(0), f.get(1), f.get(2)])
+    }
+
+    /// Returns the power delivered by this wrench to a body moving with the
+    /// given twist, both written in the same frame.
+    pub fn power(&self, twist: &Twist) -> f32 {
+        self.torque * twist.angular + self.force * twist.linear
+    }
+}
+
+impl Add for Wrench {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Wrench::new(self.torque + rhs.torque, self.force + rhs.force)
+    }
+}
+
+impl Neg for Wrench {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Wrench::new(-self.torque, -self.force)
+    }
+}
+
+impl Mul<f32> for Wrench {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Wrench::new(self.torque * rhs, self.force * rhs)
+    }
+}
+
+/// Formats the transformation as its 4x4 homogeneous matrix, honouring the
+/// precision (e.g. `{:.3}`).
+impl Display for SE3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.to_matrix(), f)
+    }
+}
+
+/// Entry-wise approximate equality of the homogeneous matrices.
+impl ApproxEq for SE3 {
+    type Tolerance = f32;
+
+    fn default_epsilon() -> f32 {
+        f32::default_epsilon()
+    }
+
+    fn default_max_relative() -> f32 {
+        f32::default_max_relative()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.to_matrix().abs_diff_eq(&other.to_matrix(), epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+        self.to_matrix()
+            .relative_eq(&other.to_matrix(), epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f32, max_ulps: u32) -> bool {
+        self.to_matrix()
+            .ulps_eq(&other.to_matrix(), epsilon, max_ulps)
+    }
+}
+
+/// Composition of rigid transformations; `a * b` applies `b` first.
+impl Mul for SE3 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        SE3::new(
+            self.rotation * rhs.rotation,
+            self.rotation * rhs.translation + self.translation,
+        )
+    }
+}
+
+/// Unchecked form of `SE3` that deserialization goes through.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct SE3Repr {
+    rotation: ArrayMatrix<f32, 3, 3>,
+    translation: ArrayVector<f32, 3>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SE3Repr> for SE3 {
+    type Error = String;
+
+    fn try_from(repr: SE3Repr) -> Result<Self, Self::Error> {
+        if !is_rotation(&repr.rotation) {
+            return Err(
+                "Rotation of a rigid transformation must be orthonormal with unit \
+                        determinant."
+                    .to_string(),
+            );
+        }
+
+        Ok(SE3::new(repr.rotation, repr.translation))
+    }
+}
@@ -0,0 +1,303 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+//! Spherical wrist arm module.
+//!
+//! Closed-form inverse kinematics for 6R arms with an ortho-parallel base and
+//! a spherical wrist, the geometry of most industrial manipulators. Follows
+//! the parameterization of Brandstötter, Angerer and Hofbaur, "An analytical
+//! solution of the inverse kinematics problem of industrial serial
+//! manipulators with an ortho-parallel basis and a spherical wrist" (2014).
+//!
+//! Such an arm reaches a generic pose in up to eight configurations: two for
+//! the shoulder, two for the elbow and two for the wrist. All are computed
+//! at once, without iteration.
+
+use crate::math::arrayalgebra::{make_array_matrix, make_array_vector, ArrayMatrix};
+use crate::math::rotations::wrap_angle;
+use crate::math::spatial::SE3;
+use std::f32::consts::PI;
+
+/// Threshold on the squared sine of the fifth joint below which the wrist is
+/// treated as singular, i.e. the fourth and sixth axes as aligned.
+const WRIST_SINGULARITY_TOLERANCE: f32 = 1e-6;
+
+/// Slack allowed on the cosines of the law of cosines before a pose is deemed
+/// out of reach, absorbing rounding at a fully stretched or folded elbow.
+const REACH_TOLERANCE: f32 = 1e-5;
+
+/// Arc cosine of a value that may stray slightly outside [-1, 1] through
+/// rounding; NaN (an unreachable branch) if it strays further.
+fn reach_acos(cosine: f32) -> f32 {
+    if cosine.abs() > 1.0 + REACH_TOLERANCE {
+        f32::NAN
+    } else {
+        cosine.clamp(-1.0, 1.0).acos()
+    }
+}
+
+/// Joint configuration of a 6R arm, in radians.
+pub type JointAngles = [f32; 6];
+
+/// Geometry of a 6R arm with an ortho-parallel base and spherical wrist.
+///
+/// With all joints at zero (after applying offsets and sign corrections) the
+/// arm points straight up the base z axis: `c1` is the shoulder height,
+/// `a1` and `b` the shoulder's offsets along x and y, `c2` the upper arm
+/// length, `a2` and `c3` the elbow's offset and forearm length, and `c4` the
+/// distance from the wrist center to the flange. `sign_corrections` (each +1
+/// or -1) and `offsets` map the robot's joint values to the model's, as
+/// `model = robot * sign - offset`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SphericalWristArm {
+    pub a1: f32,
+    pub a2: f32,
+    pub b: f32,
+    pub c1: f32,
+    pub c2: f32,
+    pub c3: f32,
+    pub c4: f32,
+    pub offsets: JointAngles,
+    pub sign_corrections: JointAngles,
+}
+
+/// One of the (up to) eight configurations reaching a pose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArmBranch {
+    /// Shoulder turned half around, reaching back over the base axis.
+    pub shoulder_flipped: bool,
+
+    /// Second of the two elbow configurations for the shoulder.
+    pub elbow_flipped: bool,
+
+    /// Wrist flipped, i.e. the fifth joint negated and the fourth and sixth
+    /// turned half around.
+    pub wrist_flipped: bool,
+}
+
+impl ArmBranch {
+    /// All eight branches, in the order of `inverse`'s results.
+    pub fn all() -> [ArmBranch; 8] {
+        std::array::from_fn(|i| ArmBranch {
+            shoulder_flipped: i & 2 != 0,
+            elbow_flipped: i & 1 != 0,
+            wrist_flipped: i & 4 != 0,
+        })
+    }
+
+    fn index(&self) -> usize {
+        (self.wrist_flipped as usize) * 4
+            + (self.shoulder_flipped as usize) * 2
+            + (self.elbow_flipped as usize)
+    }
+}
+
+impl SphericalWristArm {
+    /// Builds an arm with no joint offsets or sign corrections.
+    pub fn new(a1: f32, a2: f32, b: f32, c1: f32, c2: f32, c3: f32, c4: f32) -> Self {
+        SphericalWristArm {
+            a1,
+            a2,
+            b,
+            c1,
+            c2,
+            c3,
+            c4,
+            offsets: [0.0; 6],
+            sign_corrections: [1.0; 6],
+        }
+    }
+
+    /// Returns the pose of the flange in the base frame.
+    pub fn forward(&self, joints: &JointAngles) -> SE3 {
+        let q: JointAngles =
+            std::array::from_fn(|i| joints[i] * self.sign_corrections[i] - self.offsets[i]);
+        let (s1, c1) = q[0].sin_cos();
+        let (s23, c23) = (q[1] + q[2]).sin_cos();
+        let (s4, c4) = q[3].sin_cos();
+        let (s5, c5) = q[4].sin_cos();
+        let (s6, c6) = q[5].sin_cos();
+
+        // Wrist center.
+        let psi3 = self.a2.atan2(self.c3);
+        let k = (self.a2 * self.a2 + self.c3 * self.c3).sqrt();
+        let cx1 = self.c2 * q[1].sin() + k * (q[1] + q[2] + psi3).sin() + self.a1;
+        let cz1 = self.c2 * q[1].cos() + k * (q[1] + q[2] + psi3).cos();
+        let center = make_array_vector([
+            cx1 * c1 - self.b * s1,
+            cx1 * s1 + self.b * c1,
+            cz1 + self.c1,
+        ]);
+
+        // Rz(q1) Ry(q2 + q3), then the wrist's Rz(q4) Ry(q5) Rz(q6).
+        let arm: ArrayMatrix<f32, 3, 3> = make_array_matrix([
+            [c1 * c23, -s1, c1 * s23],
+            [s1 * c23, c1, s1 * s23],
+            [-s23, 0.0, c23],
+        ]);
+        let wrist = make_array_matrix([
+            [c4 * c5 * c6 - s4 * s6, -c4 * c5 * s6 - s4 * c6, c4 * s5],
+            [s4 * c5 * c6 + c4 * s6, -s4 * c5 * s6 + c4 * c6, s4 * s5],
+            [-s5 * c6, s5 * s6, c5],
+        ]);
+        let rotation = arm * wrist;
+
+        SE3::new(rotation, center + rotation.column(2) * self.c4)
+    }
+
+    /// Returns the joint angles, wrapped to (-pi, pi], reaching the pose in
+    /// each of the eight branches (see `ArmBranch::all` for the order), or
+    /// `None` for branches that cannot reach it.
+    ///
+    /// At a wrist singularity (fifth joint at zero or a half turn) the fourth
+    /// and sixth axes are aligned and only their combined rotation is
+    /// determined; the fourth joint is then set to zero (a half turn in the
+    /// flipped wrist branch) and the sixth takes up the whole rotation.
+    pub fn inverse(&self, pose: &SE3) -> [Option<JointAngles>; 8] {
+        let r = pose.rotation();
+        let center = pose.translation() - r.column(2) * self.c4;
+        let (cx0, cy0, cz0) = (center.x(), center.y(), center.z());
+
+        // Shoulder.
+        let nx1 = (cx0 * cx0 + cy0 * cy0 - self.b * self.b).sqrt() - self.a1;
+        let tmp1 = cy0.atan2(cx0);
+        let tmp2 = self.b.atan2(nx1 + self.a1);
+        let theta1 = [tmp1 - tmp2, tmp1 + tmp2 - PI];
+
+        // Upper arm and elbow, for either shoulder.
+        let height = cz0 - self.c1;
+        let s1_squared = nx1 * nx1 + height * height;
+        let reach_back = nx1 + 2.0 * self.a1;
+        let s2_squared = reach_back * reach_back + height * height;
+        let kappa_squared = self.a2 * self.a2 + self.c3 * self.c3;
+        let c2_squared = self.c2 * self.c2;
+        let psi3 = self.a2.atan2(self.c3);
+
+        let (s1, s2) = (s1_squared.sqrt(), s2_squared.sqrt());
+        let alpha1 = reach_acos((s1_squared + c2_squared - kappa_squared) / (2.0 * s1 * self.c2));
+        let alpha2 = reach_acos((s2_squared + c2_squared - kappa_squared) / (2.0 * s2 * self.c2));
+        let beta1 = nx1.atan2(height);
+        let beta2 = reach_back.atan2(height);
+        let theta2 = [
+            -alpha1 + beta1,
+            alpha1 + beta1,
+            -alpha2 - beta2,
+            alpha2 - beta2,
+        ];
+
+        let elbow = 2.0 * self.c2 * kappa_squared.sqrt();
+        let gamma1 = reach_acos((s1_squared - c2_squared - kappa_squared) / elbow);
+        let gamma2 = reach_acos((s2_squared - c2_squared - kappa_squared) / elbow);
+        let theta3 = [gamma1 - psi3, -gamma1 - psi3, gamma2 - psi3, -gamma2 - psi3];
+
+        let mut solutions = [None; 8];
+        for arm in 0..4 {
+            let (sin1, cos1) = theta1[arm / 2].sin_cos();
+            let (s23, c23) = (theta2[arm] + theta3[arm]).sin_cos();
+            let m = r.get(0, 2) * s23 * cos1 + r.get(1, 2) * s23 * sin1 + r.get(2, 2) * c23;
+
+            let sin5_squared = (1.0 - m * m).max(0.0);
+            let theta5 = sin5_squared.sqrt().atan2(m);
+            let (theta4, theta6) = if sin5_squared <= WRIST_SINGULARITY_TOLERANCE {
+                // The wrist reduces to Rz(q4 +- q6) (times Ry(pi) if the fifth
+                // joint is a half turn); read the angle off the second column
+                // of Ry(q2 + q3)^T Rz(q1)^T R.
+                let y = r.get(0, 1) * c23 * cos1 + r.get(1, 1) * c23 * sin1 - r.get(2, 1) * s23;
+                let x = r.get(1, 1) * cos1 - r.get(0, 1) * sin1;
+                (0.0, (-m.signum() * y).atan2(x))
+            } else {
+                (
+                    (r.get(1, 2) * cos1 - r.get(0, 2) * sin1).atan2(
+                        r.get(0, 2) * c23 * cos1 + r.get(1, 2) * c23 * sin1 - r.get(2, 2) * s23,
+                    ),
+                    (r.get(0, 1) * s23 * cos1 + r.get(1, 1) * s23 * sin1 + r.get(2, 1) * c23)
+                        .atan2(
+                            -r.get(0, 0) * s23 * cos1
+                                - r.get(1, 0) * s23 * sin1
+                                - r.get(2, 0) * c23,
+                        ),
+                )
+            };
+
+            let model = [
+                [
+                    theta1[arm / 2],
+                    theta2[arm],
+                    theta3[arm],
+                    theta4,
+                    theta5,
+                    theta6,
+                ],
+                [
+                    theta1[arm / 2],
+                    theta2[arm],
+                    theta3[arm],
+                    theta4 + PI,
+                    -theta5,
+                    theta6 - PI,
+                ],
+            ];
+            for (wrist, q) in model.iter().enumerate() {
+                if q.iter().any(|angle| angle.is_nan()) {
+                    continue;
+                }
+                solutions[wrist * 4 + arm] = Some(std::array::from_fn(|i| {
+                    wrap_angle((q[i] + self.offsets[i]) * self.sign_corrections[i])
+                }));
+            }
+        }
+
+        solutions
+    }
+
+    /// Returns the joint angles reaching the pose in the given branch, if it
+    /// can.
+    pub fn inverse_in_branch(&self, pose: &SE3, branch: ArmBranch) -> Option<JointAngles> {
+        self.inverse(pose)[branch.index()]
+    }
+
+    /// Returns the joint angles reaching the pose that are closest to the
+    /// seed configuration, e.g. the arm's current one, comparing angles
+    /// modulo a full turn.
+    pub fn inverse_nearest(&self, pose: &SE3, seed: &JointAngles) -> Option<JointAngles> {
+        let distance = |q: &JointAngles| {
+            q.iter()
+                .zip(seed.iter())
+                .map(|(a, b)| wrap_angle(a - b).powi(2))
+                .sum::<f32>()
+        };
+
+        self.inverse(pose)
+            .into_iter()
+            .flatten()
+            .min_by(|a, b| distance(a).total_cmp(&distance(b)))
+    }
+}
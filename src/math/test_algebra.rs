@@ -29,6 +29,8 @@ SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 #[cfg(test)]
 mod tests {
+    use crate::math::algebra::{gram_schmidt, CrossProduct, InnerProductSpace};
+    use crate::math::approx::ApproxEq;
     use crate::math::arrayalgebra::*;
 
     #[test]
@@ -61,7 +63,432 @@ mod tests {
         assert_eq!(e1 * a, 1.0);
     }
 
+    #[test]
+    fn vector3f_cross_product() {
+        let e1 = make_array_vector([1.0, 0.0, 0.0]);
+        let e2 = make_array_vector([0.0, 1.0, 0.0]);
+        let e3 = make_array_vector([0.0, 0.0, 1.0]);
+        assert_eq!(e1.cross(e2), e3);
+        assert_eq!(e2.cross(e1), -e3);
+
+        let a = make_array_vector([1.0, 2.0, 3.0]);
+        let b = make_array_vector([-2.0, 0.5, 4.0]);
+        let c = a.cross(b);
+        assert_eq!(c * a, 0.0);
+        assert_eq!(c * b, 0.0);
+        assert_eq!(a.cross(a), make_array_vector([0.0, 0.0, 0.0]));
+    }
+
+    #[test]
+    fn vector4f_homogeneous_cross_product() {
+        let a = make_array_vector([1.0, 0.0, 0.0, 1.0]);
+        let b = make_array_vector([0.0, 1.0, 0.0, 1.0]);
+        assert_eq!(a.cross(b), make_array_vector([0.0, 0.0, 1.0, 0.0]));
+    }
+
+    #[test]
+    fn vector3f_inner_product_space() {
+        let a: ArrayVector<f32, 3> = make_array_vector([3.0, 0.0, 4.0]);
+        let b = make_array_vector([0.0, 1.0, 0.0]);
+        assert_eq!(a.dot(b), 0.0);
+        assert_eq!(a.squared_norm(), 25.0);
+        assert_eq!(a.norm(), 5.0);
+        assert_eq!(a.distance(a), 0.0);
+        assert_eq!(
+            make_array_vector([1.0_f32, 1.0]).distance(make_array_vector([4.0, 5.0])),
+            5.0
+        );
+        assert_eq!(a.normalize(), Some(make_array_vector([0.6, 0.0, 0.8])));
+        assert_eq!(make_array_vector([0.0_f32, 0.0, 0.0]).normalize(), None);
+    }
+
+    #[test]
+    fn vector3d_generic_scalar() {
+        let a: ArrayVector<f64, 3> = make_array_vector([1.0, 2.0, 2.0]);
+        let m: ArrayMatrix<f64, 3, 3> = ArrayMatrix::identity();
+        assert_eq!(a.norm(), 3.0);
+        assert_eq!(m * a, a);
+        assert_eq!(a * 0.5, make_array_vector([0.5, 1.0, 1.0]));
+        assert!((m.inverse().unwrap() * a).distance(a) < 1e-12);
+    }
+
+    #[test]
+    fn matrix_vector_multiplication() {
+        let m = make_array_matrix([[1.0, 2.0, 0.0], [0.0, 1.0, -1.0]]);
+        let v = make_array_vector([1.0, 1.0, 2.0]);
+        assert_eq!(m * v, make_array_vector([3.0, -1.0]));
+    }
+
+    #[test]
+    fn matrix_matrix_multiplication() {
+        let a = make_array_matrix([[1.0, 2.0], [3.0, 4.0]]);
+        let b = make_array_matrix([[0.0, 1.0, 2.0], [1.0, 0.0, -1.0]]);
+        let c = make_array_matrix([[2.0, 1.0, 0.0], [4.0, 3.0, 2.0]]);
+        assert_eq!(a * b, c);
+        assert_eq!(ArrayMatrix::identity() * a, a);
+        assert_eq!(a * ArrayMatrix::<f32, 2, 3>::zero(), ArrayMatrix::zero());
+    }
+
+    #[test]
+    fn matrix_transpose() {
+        let m = make_array_matrix([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let t = make_array_matrix([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]);
+        assert_eq!(m.transpose(), t);
+        assert_eq!(t.transpose(), m);
+        assert_eq!(m.get(1, 2), 6.0);
+    }
+
+    fn assert_identity<const N: usize>(m: ArrayMatrix<f32, N, N>) {
+        for i in 0..N {
+            for j in 0..N {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((m.get(i, j) - expected).abs() < 1e-4, "{m:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn matrix_determinant() {
+        assert_eq!(
+            make_array_matrix([[3.0, 1.0], [4.0, 2.0]]).determinant(),
+            2.0
+        );
+        assert_eq!(
+            make_array_matrix([[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 2.0]]).determinant(),
+            6.0
+        );
+        assert_eq!(ArrayMatrix::<f32, 4, 4>::identity().determinant(), 1.0);
+
+        // A permutation swapping two rows of the identity has determinant -1.
+        let mut rows = [[0.0; 5]; 5];
+        for (i, row) in rows.iter_mut().enumerate() {
+            row[[1, 0, 2, 3, 4][i]] = 2.0;
+        }
+        assert_eq!(make_array_matrix(rows).determinant(), -32.0);
+        assert_eq!(
+            make_array_matrix([
+                [1.0, 2.0, 3.0, 4.0],
+                [2.0, 4.0, 6.0, 8.0],
+                [0.0, 1.0, 0.0, 1.0],
+                [1.0, 0.0, 1.0, 0.0]
+            ])
+            .determinant(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn matrix_inverse() {
+        let m2 = make_array_matrix([[3.0, 1.0], [4.0, 2.0]]);
+        assert_identity(m2 * m2.inverse().unwrap());
+
+        let m3 = make_array_matrix([[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 2.0]]);
+        assert_identity(m3 * m3.inverse().unwrap());
+        assert_identity(m3.inverse().unwrap() * m3);
+
+        let m4 = make_array_matrix([
+            [4.0, 1.0, 0.0, 2.0],
+            [1.0, 3.0, 1.0, 0.0],
+            [0.0, 1.0, 5.0, 1.0],
+            [2.0, 0.0, 1.0, 6.0],
+        ]);
+        assert_identity(m4 * m4.inverse().unwrap());
+        assert!((m4.determinant() * m4.inverse().unwrap().determinant() - 1.0).abs() < 1e-4);
+
+        let m5 = make_array_matrix([
+            [4.0, 1.0, 0.0, 2.0, 1.0],
+            [1.0, 3.0, 1.0, 0.0, 0.0],
+            [0.0, 1.0, 5.0, 1.0, 2.0],
+            [2.0, 0.0, 1.0, 6.0, 1.0],
+            [0.0, 2.0, 0.0, 1.0, 7.0],
+        ]);
+        assert_identity(m5 * m5.inverse().unwrap());
+
+        assert!(make_array_matrix([[1.0, 2.0], [2.0, 4.0]])
+            .inverse()
+            .is_none());
+        assert!(ArrayMatrix::<f32, 5, 5>::zero().inverse().is_none());
+    }
+
+    #[test]
+    fn matrix_inverse_near_singular() {
+        // Singular, but rounding leaves a small non-zero determinant in f32.
+        let m3 = make_array_matrix([[1.0f32, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        assert!(m3.inverse().is_none());
+
+        let m4 = make_array_matrix([
+            [1.0f32, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 10.0, 11.0, 12.0],
+            [0.1, 0.7, 0.3, 0.9],
+        ]);
+        assert!(m4.inverse().is_none());
+
+        let m5 = make_array_matrix([
+            [1.0f32, 2.0, 3.0, 4.0, 5.0],
+            [6.0, 7.0, 8.0, 9.0, 10.0],
+            [11.0, 12.0, 13.0, 14.0, 15.0],
+            [0.5, 0.1, 0.9, 0.3, 0.7],
+            [0.2, 0.8, 0.4, 0.6, 0.1],
+        ]);
+        assert!(m5.inverse().is_none());
+
+        // Poorly scaled but well-conditioned matrices remain invertible.
+        let scaled = make_array_matrix([[1e-3f32, 0.0, 0.0], [0.0, 2e-3, 0.0], [0.0, 0.0, 4e-3]]);
+        assert_identity(scaled * scaled.inverse().unwrap());
+    }
+
     // fn vector3f_in_frame() {
 
     // }
+
+    #[test]
+    fn vector3f_compound_assignment() {
+        let mut a: ArrayVector<f32, 3> = make_array_vector([1.0, 2.0, 3.0]);
+        a += make_array_vector([1.0, 1.0, 1.0]);
+        assert_eq!(a, make_array_vector([2.0, 3.0, 4.0]));
+        a -= make_array_vector([2.0, 0.0, 1.0]);
+        assert_eq!(a, make_array_vector([0.0, 3.0, 3.0]));
+        a *= 2.0;
+        assert_eq!(a, make_array_vector([0.0, 6.0, 6.0]));
+        assert_eq!(a - a, make_array_vector([0.0, 0.0, 0.0]));
+    }
+
+    #[test]
+    fn vector4f_component_access() {
+        let mut a: ArrayVector<f32, 4> = make_array_vector([1.0, 2.0, 3.0, 4.0]);
+        assert_eq!((a.x(), a.y(), a.z(), a.w()), (1.0, 2.0, 3.0, 4.0));
+        assert_eq!(a[2], 3.0);
+
+        a[0] = 5.0;
+        a.as_mut_slice()[1] = 6.0;
+        assert_eq!(a.as_slice(), &[5.0, 6.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn vector2f_missing_component() {
+        let a: ArrayVector<f32, 2> = make_array_vector([1.0, 2.0]);
+        a.z();
+    }
+
+    #[test]
+    fn vector3f_conversions() {
+        let a: ArrayVector<f32, 3> = [1.0, 2.0, 3.0].into();
+        let array: [f32; 3] = a.into();
+        assert_eq!(array, [1.0, 2.0, 3.0]);
+
+        let buffer = [1.0, 2.0, 3.0, 4.0];
+        assert_eq!(ArrayVector::try_from_slice(&buffer[..3]).unwrap(), a);
+        assert!(ArrayVector::<f32, 3>::try_from(&buffer[..]).is_err());
+
+        let doubled: ArrayVector<f32, 3> = a.into_iter().map(|x| x * 2.0).collect();
+        assert_eq!(doubled, a * 2.0);
+        assert_eq!((&a).into_iter().sum::<f32>(), 6.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn vector3f_collect_wrong_length() {
+        let _: ArrayVector<f32, 3> = [1.0, 2.0].into_iter().collect();
+    }
+
+    #[test]
+    fn vector_and_matrix_display() {
+        let a: ArrayVector<f32, 3> = make_array_vector([1.0, -2.5, 3.0]);
+        assert_eq!(format!("{a}"), "[1, -2.5, 3]");
+        assert_eq!(format!("{a:.2}"), "[1.00, -2.50, 3.00]");
+
+        let m: ArrayMatrix<f32, 2, 2> = make_array_matrix([[1.0, -10.0], [0.5, 2.0]]);
+        assert_eq!(format!("{m:.1}"), "[  1.0, -10.0]\n[  0.5,   2.0]");
+    }
+
+    #[test]
+    fn vector_outer_product() {
+        let v: ArrayVector<f32, 2> = make_array_vector([1.0, 2.0]);
+        let w = make_array_vector([3.0, 4.0, 5.0]);
+        let m = outer(v, w);
+        assert_eq!(m, make_array_matrix([[3.0, 4.0, 5.0], [6.0, 8.0, 10.0]]));
+
+        // (v w^T) u = v (w . u).
+        let u = make_array_vector([1.0, 0.0, -1.0]);
+        assert_eq!(m * u, v * (w * u));
+    }
+
+    fn diagonal<const C: usize>(values: ArrayVector<f64, C>) -> ArrayMatrix<f64, C, C> {
+        let mut rows = [[0.0; C]; C];
+        for (i, row) in rows.iter_mut().enumerate() {
+            row[i] = values[i];
+        }
+        make_array_matrix(rows)
+    }
+
+    #[test]
+    fn matrix_svd_reconstruction() {
+        let a: ArrayMatrix<f64, 3, 2> = make_array_matrix([[3.0, 2.0], [2.0, 3.0], [2.0, -2.0]]);
+        let svd = a.svd();
+        assert!(svd.singular_values[0] >= svd.singular_values[1]);
+        assert!((svd.u * diagonal(svd.singular_values) * svd.v.transpose()).abs_diff_eq(&a, 1e-12));
+        assert!((svd.v.transpose() * svd.v).abs_diff_eq(&ArrayMatrix::identity(), 1e-12));
+        assert!((svd.u.transpose() * svd.u).abs_diff_eq(&ArrayMatrix::identity(), 1e-12));
+
+        // Singular values of the transpose agree; the third is zero.
+        let svd_t = a.transpose().svd();
+        assert!((svd_t.singular_values[0] - svd.singular_values[0]).abs() < 1e-12);
+        assert!((svd_t.singular_values[1] - svd.singular_values[1]).abs() < 1e-12);
+        assert!(svd_t.singular_values[2].abs() < 1e-12);
+        assert_eq!(svd_t.rank(1e-9), 2);
+    }
+
+    #[test]
+    fn matrix_pseudo_inverse() {
+        // Square and invertible: the pseudo-inverse is the inverse.
+        let a: ArrayMatrix<f64, 3, 3> =
+            make_array_matrix([[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 2.0]]);
+        assert!(a.pinv().abs_diff_eq(&a.inverse().unwrap(), 1e-12));
+
+        // Wide (e.g. a redundant manipulator's Jacobian): a right inverse.
+        let j: ArrayMatrix<f64, 2, 3> = make_array_matrix([[1.0, 2.0, 3.0], [0.0, 1.0, -1.0]]);
+        let j_pinv = j.pinv();
+        assert!((j * j_pinv).abs_diff_eq(&ArrayMatrix::identity(), 1e-12));
+
+        // Rank-deficient: the Penrose conditions still hold.
+        let s: ArrayMatrix<f64, 3, 3> =
+            make_array_matrix([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 0.0, 1.0]]);
+        let s_pinv = s.pinv();
+        assert!((s * s_pinv * s).abs_diff_eq(&s, 1e-10));
+        assert!((s_pinv * s * s_pinv).abs_diff_eq(&s_pinv, 1e-10));
+        assert!((s * s_pinv).transpose().abs_diff_eq(&(s * s_pinv), 1e-10));
+    }
+
+    #[test]
+    fn matrix_symmetric_eigen() {
+        let a: ArrayMatrix<f64, 3, 3> =
+            make_array_matrix([[4.0, 1.0, -2.0], [1.0, 2.0, 0.0], [-2.0, 0.0, 3.0]]);
+        let eigen = a.symmetric_eigen();
+        let (values, vectors) = (eigen.eigenvalues, eigen.eigenvectors);
+
+        assert!(values[0] >= values[1] && values[1] >= values[2]);
+        assert!((values[0] + values[1] + values[2] - 9.0).abs() < 1e-12);
+        assert!((vectors.transpose() * vectors).abs_diff_eq(&ArrayMatrix::identity(), 1e-12));
+        assert!((vectors * diagonal(values) * vectors.transpose()).abs_diff_eq(&a, 1e-12));
+
+        // A diagonal matrix is already decomposed, up to ordering.
+        let d: ArrayMatrix<f64, 3, 3> =
+            make_array_matrix([[1.0, 0.0, 0.0], [0.0, 3.0, 0.0], [0.0, 0.0, 2.0]]);
+        let eigen = d.symmetric_eigen();
+        assert_eq!(eigen.eigenvalues, make_array_vector([3.0, 2.0, 1.0]));
+        assert_eq!(eigen.eigenvectors.get(1, 0).abs(), 1.0);
+    }
+
+    #[test]
+    fn vector_gram_schmidt() {
+        let vectors: [ArrayVector<f64, 3>; 2] = [
+            make_array_vector([2.0, 0.0, 0.0]),
+            make_array_vector([1.0, 1.0, 0.0]),
+        ];
+        let basis = gram_schmidt(&vectors).unwrap();
+        assert!(basis[0].abs_diff_eq(&make_array_vector([1.0, 0.0, 0.0]), 1e-12));
+        assert!(basis[1].abs_diff_eq(&make_array_vector([0.0, 1.0, 0.0]), 1e-12));
+
+        let dependent = [vectors[0], vectors[1], vectors[0] + vectors[1] * 3.0];
+        assert!(gram_schmidt(&dependent).is_none());
+
+        let drifted: ArrayMatrix<f64, 3, 3> =
+            make_array_matrix([[1.0, 0.01, 0.0], [0.0, 1.0, 0.02], [0.01, 0.0, 1.0]]);
+        let q = drifted.orthonormalize().unwrap();
+        assert!((q.transpose() * q).abs_diff_eq(&ArrayMatrix::identity(), 1e-12));
+        assert!(q.determinant() > 0.0);
+    }
+
+    #[test]
+    fn matrix_blocks_and_stacking() {
+        let a: ArrayMatrix<f32, 2, 2> = make_array_matrix([[1.0, 2.0], [3.0, 4.0]]);
+        let b: ArrayMatrix<f32, 2, 1> = make_array_matrix([[5.0], [6.0]]);
+        let c: ArrayMatrix<f32, 1, 3> = make_array_matrix([[7.0, 8.0, 9.0]]);
+
+        let ab: ArrayMatrix<f32, 2, 3> = a.hstack(&b);
+        let abc: ArrayMatrix<f32, 3, 3> = ab.vstack(&c);
+        assert_eq!(
+            abc,
+            make_array_matrix([[1.0, 2.0, 5.0], [3.0, 4.0, 6.0], [7.0, 8.0, 9.0]])
+        );
+        assert_eq!(
+            abc.block::<2, 2>(1, 1),
+            make_array_matrix([[4.0, 6.0], [8.0, 9.0]])
+        );
+        assert_eq!(abc.row(2), make_array_vector([7.0, 8.0, 9.0]));
+        assert_eq!(abc.column(2), make_array_vector([5.0, 6.0, 9.0]));
+
+        let mut jacobian = ArrayMatrix::<f32, 6, 2>::zero();
+        jacobian.set_block(3, 0, &a);
+        assert_eq!(jacobian.get(4, 1), 4.0);
+        assert_eq!(jacobian.get(0, 0), 0.0);
+
+        let columns = [make_array_vector([1.0, 3.0]), make_array_vector([2.0, 4.0])];
+        assert_eq!(ArrayMatrix::from_columns(columns), a);
+        assert_eq!(ArrayMatrix::from_rows([a.row(0), a.row(1)]), a);
+
+        let v: ArrayVector<f32, 5> = concatenate(
+            make_array_vector([1.0, 2.0]),
+            make_array_vector([3.0, 4.0, 5.0]),
+        );
+        assert_eq!(v, make_array_vector([1.0, 2.0, 3.0, 4.0, 5.0]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn matrix_block_out_of_bounds() {
+        ArrayMatrix::<f32, 3, 3>::identity().block::<2, 2>(2, 0);
+    }
+
+    #[test]
+    fn vector_constructors_and_normalization() {
+        assert_eq!(
+            ArrayVector::<f32, 3>::zeros(),
+            make_array_vector([0.0, 0.0, 0.0])
+        );
+        assert_eq!(ArrayVector::<f32, 2>::ones(), make_array_vector([1.0, 1.0]));
+        assert_eq!(
+            ArrayVector::<f32, 3>::basis(1),
+            make_array_vector([0.0, 1.0, 0.0])
+        );
+
+        let v: ArrayVector<f64, 2> = make_array_vector([3.0, 4.0]);
+        assert_eq!(v.norm(), 5.0);
+        assert!(v
+            .normalize()
+            .unwrap()
+            .abs_diff_eq(&make_array_vector([0.6, 0.8]), 1e-12));
+        assert_eq!(ArrayVector::<f64, 2>::zeros().normalize(), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn array_serde_round_trip() {
+        let v: ArrayVector<f64, 3> = make_array_vector([1.0, 2.5, -3.0]);
+        assert_eq!(serde_json::to_string(&v).unwrap(), "[1.0,2.5,-3.0]");
+        assert_eq!(
+            serde_json::from_str::<ArrayVector<f64, 3>>("[1.0,2.5,-3.0]").unwrap(),
+            v
+        );
+        assert!(serde_json::from_str::<ArrayVector<f64, 3>>("[1.0,2.5]").is_err());
+        assert!(serde_json::from_str::<ArrayVector<f64, 3>>("[1.0,2.5,3.0,4.0]").is_err());
+
+        let m: ArrayMatrix<f32, 2, 3> = make_array_matrix([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(json, "[[1.0,2.0,3.0],[4.0,5.0,6.0]]");
+        assert_eq!(
+            serde_json::from_str::<ArrayMatrix<f32, 2, 3>>(&json).unwrap(),
+            m
+        );
+
+        // Beyond the 32 entries serde supports for plain arrays.
+        let long = ArrayVector::<f32, 40>::ones();
+        let json = serde_json::to_string(&long).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ArrayVector<f32, 40>>(&json).unwrap(),
+            long
+        );
+    }
 }
@@ -0,0 +1,78 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+#[cfg(test)]
+mod tests {
+    use crate::math::approx::ApproxEq;
+    use crate::math::arrayalgebra::*;
+    use crate::math::rotations::*;
+    use crate::math::spatial::*;
+
+    #[test]
+    fn approx_scalars() {
+        let sum = [0.1f64, 0.2].iter().sum::<f64>();
+        assert_ne!(sum, 0.3);
+        assert!(sum.approx_eq(&0.3));
+        assert!(1.0f64.abs_diff_eq(&1.05, 0.1));
+        assert!(!1.0f64.abs_diff_eq(&1.2, 0.1));
+
+        // Large magnitudes need a relative tolerance.
+        assert!(!1.0e6f32.abs_diff_eq(&1.0001e6, 1e-3));
+        assert!(1.0e6f32.relative_eq(&1.0001e6, 1e-3, 1e-3));
+
+        let next = f32::from_bits(1.0f32.to_bits() + 2);
+        assert!(1.0f32.ulps_eq(&next, 0.0, 2));
+        assert!(!1.0f32.ulps_eq(&next, 0.0, 1));
+        assert!(!1.0f32.ulps_eq(&-1.0, 0.0, 4));
+        assert!(0.0f32.ulps_eq(&-0.0, 0.0, 0));
+    }
+
+    #[test]
+    fn approx_vectors_and_matrices() {
+        let a: ArrayVector<f32, 3> = make_array_vector([1.0, 2.0, 3.0]);
+        let b = make_array_vector([1.0, 2.0 + 1e-6, 3.0]);
+        assert!(a.abs_diff_eq(&b, 1e-5));
+        assert!(!a.abs_diff_eq(&make_array_vector([1.0, 2.1, 3.0]), 1e-5));
+
+        let m: ArrayMatrix<f64, 2, 2> = make_array_matrix([[2.0, 1.0], [1.0, 1.0]]);
+        assert!((m * m.inverse().unwrap()).abs_diff_eq(&ArrayMatrix::identity(), 1e-12));
+    }
+
+    #[test]
+    fn approx_transforms() {
+        let axis = make_array_vector([0.0, 0.0, 1.0]);
+        let q = quaternion_from_axis_angle(axis, 0.7);
+        let twice = quaternion_from_axis_angle(axis, 0.35) * quaternion_from_axis_angle(axis, 0.35);
+        assert!(q.abs_diff_eq(&twice, 1e-6));
+
+        let pose = SE3::from_quaternion(&q, make_array_vector([1.0, -2.0, 0.5]));
+        assert!((pose * pose.inverse()).abs_diff_eq(&SE3::identity(), 1e-6));
+        assert!(!pose.abs_diff_eq(&SE3::identity(), 1e-6));
+    }
+}
@@ -0,0 +1,85 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+#[cfg(test)]
+mod tests {
+    use crate::math::algebra::Real;
+    use crate::math::arrayalgebra::*;
+    use crate::math::dual::*;
+
+    #[test]
+    fn dual_arithmetic_derivatives() {
+        // d/dx (x^2 + 3) / x = 1 - 3 / x^2.
+        let (value, slope) = derivative(|x: Dual<f64>| (x * x + Dual::constant(3.0)) / x, 2.0);
+        assert!((value - 3.5).abs() < 1e-12);
+        assert!((slope - 0.25).abs() < 1e-12);
+
+        let (root, slope) = derivative(|x: Dual<f64>| x.sqrt(), 4.0);
+        assert!((root - 2.0).abs() < 1e-12);
+        assert!((slope - 0.25).abs() < 1e-12);
+
+        let (_, slope) = derivative(|x: Dual<f64>| (-x).abs(), 3.0);
+        assert!((slope - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn dual_through_matrix_algorithms() {
+        // d/dx det [[x, 1], [2, x]] = 2x.
+        let (det, slope) = derivative(
+            |x| {
+                let one = Dual::constant(1.0);
+                let two = Dual::constant(2.0);
+                make_array_matrix([[x, one], [two, x]]).determinant()
+            },
+            3.0,
+        );
+        assert!((det - 7.0).abs() < 1e-12);
+        assert!((slope - 6.0).abs() < 1e-12);
+
+        // d/dx |(x, 2x, 2x)| = 3.
+        let (_, slope) = derivative(|x| make_array_vector([x, x + x, x + x]).norm(), 1.5);
+        assert!((slope - 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn dual_comparisons_follow_real_part() {
+        let a = Dual::new(1.0f64, 0.0);
+        let b = Dual::new(1.0f64, 1.0);
+        assert_ne!(a, b);
+        assert_eq!(a, Dual::new(1.0, 0.0));
+        assert_eq!(a.real_cmp(&b), Some(std::cmp::Ordering::Equal));
+        assert_eq!(a.partial_cmp(&b), Some(std::cmp::Ordering::Less));
+        assert!(Dual::new(0.5f64, 2.0) < a);
+
+        // Generic equality sees derivatives, e.g. for vectors of duals.
+        let u = make_array_vector([a, a]);
+        let v = make_array_vector([a, b]);
+        assert!(u != v);
+    }
+}
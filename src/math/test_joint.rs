@@ -0,0 +1,146 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+#[cfg(test)]
+mod tests {
+    use crate::math::approx::ApproxEq;
+    use crate::math::arrayalgebra::*;
+    use crate::math::joint::*;
+    use crate::math::spatial::*;
+    use std::f32::consts::FRAC_PI_2;
+
+    fn origin() -> SE3 {
+        SE3::new(ArrayMatrix::identity(), make_array_vector([0.0, 0.0, 1.0]))
+    }
+
+    #[test]
+    fn joint_revolute_transform() {
+        let mut joint =
+            Joint::revolute(make_array_vector([0.0, 0.0, 2.0]), origin(), -2.0, 2.0).unwrap();
+        assert!(joint.transform().abs_diff_eq(&origin(), 1e-6));
+
+        joint.set_value(FRAC_PI_2).unwrap();
+        let tip = joint
+            .transform()
+            .transform_point(make_array_vector([1.0, 0.0, 0.0]));
+        assert!(tip.abs_diff_eq(&make_array_vector([0.0, 1.0, 1.0]), 1e-6));
+        assert_eq!(
+            joint.unit_twist(),
+            Twist::new(make_array_vector([0.0, 0.0, 1.0]), ArrayVector::zeros())
+        );
+
+        assert_eq!(joint.set_value(3.0), Err(JointFailure::OutOfLimits(3.0)));
+        assert_eq!(joint.value(), FRAC_PI_2);
+    }
+
+    #[test]
+    fn joint_prismatic_fixed_and_continuous() {
+        let mut slide =
+            Joint::prismatic(make_array_vector([1.0, 0.0, 0.0]), origin(), 0.5, 1.0).unwrap();
+        // Zero lies outside the limits, so the joint starts at the lower one.
+        assert_eq!(slide.value(), 0.5);
+        slide.set_value(0.75).unwrap();
+        assert!(slide
+            .transform()
+            .translation()
+            .abs_diff_eq(&make_array_vector([0.75, 0.0, 1.0]), 1e-6));
+
+        let mut weld = Joint::fixed(origin());
+        assert_eq!(weld.set_value(1.0), Err(JointFailure::FixedJoint));
+        assert!(weld.transform().abs_diff_eq(&origin(), 1e-6));
+        assert_eq!(weld.unit_twist(), Twist::zero());
+
+        let mut wheel =
+            Joint::continuous(make_array_vector([0.0, 1.0, 0.0]), SE3::identity()).unwrap();
+        assert!(wheel.set_value(100.0).is_ok());
+        assert!(Joint::continuous(ArrayVector::zeros(), SE3::identity()).is_none());
+    }
+
+    #[test]
+    fn joint_invalid_limits() {
+        let axis = make_array_vector([0.0, 0.0, 1.0]);
+        assert!(Joint::revolute(axis, origin(), 1.0, -1.0).is_none());
+        assert!(Joint::prismatic(axis, origin(), f32::NAN, 1.0).is_none());
+        assert!(Joint::prismatic(axis, origin(), 0.0, f32::NAN).is_none());
+        assert!(!JointKind::Revolute {
+            lower: f32::NAN,
+            upper: f32::NAN
+        }
+        .has_valid_limits());
+
+        // Degenerate but ordered limits pin the joint in place.
+        let pinned = Joint::revolute(axis, origin(), 0.5, 0.5).unwrap();
+        assert_eq!(pinned.value(), 0.5);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn joint_serde_round_trip() {
+        let mut joint =
+            Joint::revolute(make_array_vector([0.0, 1.0, 0.0]), origin(), -1.0, 1.0).unwrap();
+        joint.set_value(0.25).unwrap();
+
+        let json = serde_json::to_string(&joint).unwrap();
+        let restored: Joint = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, joint);
+        assert!(restored.transform().abs_diff_eq(&joint.transform(), 1e-6));
+
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["version"], 1);
+
+        let tampered = json.replace("\"value\":0.25", "\"value\":2.0");
+        assert!(serde_json::from_str::<Joint>(&tampered).is_err());
+        let tampered = json.replace("\"version\":1", "\"version\":2");
+        assert!(serde_json::from_str::<Joint>(&tampered).is_err());
+        let tampered = json.replace("\"axis\":[0.0,1.0,0.0]", "\"axis\":[0.0,0.0,0.0]");
+        assert!(serde_json::from_str::<Joint>(&tampered).is_err());
+        let tampered = json.replace("\"axis\":[0.0,1.0,0.0]", "\"axis\":[0.0,3.0,0.0]");
+        assert_ne!(tampered, json);
+        let restored: Joint = serde_json::from_str(&tampered).unwrap();
+        assert!(restored
+            .axis()
+            .abs_diff_eq(&make_array_vector([0.0, 1.0, 0.0]), 1e-6));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn joint_serde_rejects_invalid_limits() {
+        let joint =
+            Joint::prismatic(make_array_vector([1.0, 0.0, 0.0]), origin(), -1.0, 1.0).unwrap();
+        let json = serde_json::to_string(&joint).unwrap();
+
+        let inverted = json.replace(
+            "\"lower\":-1.0,\"upper\":1.0",
+            "\"lower\":1.0,\"upper\":-1.0",
+        );
+        assert_ne!(inverted, json);
+        let error = serde_json::from_str::<Joint>(&inverted).unwrap_err();
+        assert!(error.to_string().contains("inverted or NaN"));
+    }
+}
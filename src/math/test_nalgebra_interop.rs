@@ -0,0 +1,65 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+#[cfg(all(test, feature = "nalgebra"))]
+mod tests {
+    use crate::math::approx::ApproxEq;
+    use crate::math::arrayalgebra::*;
+    use crate::math::rotations::*;
+    use crate::math::spatial::*;
+    use nalgebra::{Isometry3, Matrix2x3, Point3, Vector3};
+
+    #[test]
+    fn nalgebra_vector_and_matrix_round_trip() {
+        let v: ArrayVector<f64, 3> = make_array_vector([1.0, 2.0, 3.0]);
+        let n: Vector3<f64> = v.into();
+        assert_eq!(n, Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(ArrayVector::from(n), v);
+
+        let m: ArrayMatrix<f32, 2, 3> = make_array_matrix([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let n: Matrix2x3<f32> = m.into();
+        assert_eq!(n, Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0));
+        assert_eq!(ArrayMatrix::from(n), m);
+    }
+
+    #[test]
+    fn nalgebra_transform_round_trip() {
+        let q = quaternion_from_axis_angle(make_array_vector([1.0, 2.0, -1.0]), 0.8);
+        let pose = SE3::from_quaternion(&q, make_array_vector([0.5, -1.0, 2.0]));
+        let isometry: Isometry3<f32> = pose.into();
+
+        let point = make_array_vector([0.3, 0.1, -0.7]);
+        let expected = pose.transform_point(point);
+        let actual = isometry * Point3::new(0.3, 0.1, -0.7);
+        let actual = make_array_vector([actual.x, actual.y, actual.z]);
+        assert!(actual.abs_diff_eq(&expected, 1e-5));
+
+        assert!(SE3::from(isometry).abs_diff_eq(&pose, 1e-5));
+    }
+}
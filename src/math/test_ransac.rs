@@ -0,0 +1,139 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+#[cfg(test)]
+mod tests {
+    use crate::math::ransac::*;
+
+    /// Fits y = m x + b through the given points by least squares.
+    fn fit_line(points: &[&(f32, f32)]) -> Option<(f32, f32)> {
+        let n = points.len() as f32;
+        let (sx, sy, sxx, sxy) = points.iter().fold((0.0, 0.0, 0.0, 0.0), |acc, (x, y)| {
+            (acc.0 + x, acc.1 + y, acc.2 + x * x, acc.3 + x * y)
+        });
+        let denominator = n * sxx - sx * sx;
+        if denominator.abs() < 1e-6 {
+            return None;
+        }
+        let m = (n * sxy - sx * sy) / denominator;
+        Some((m, (sy - m * sx) / n))
+    }
+
+    fn line_residual(model: &(f32, f32), point: &(f32, f32)) -> f32 {
+        point.1 - (model.0 * point.0 + model.1)
+    }
+
+    fn contaminated_line() -> Vec<(f32, f32)> {
+        let mut points: Vec<(f32, f32)> = (0..20)
+            .map(|i| {
+                let x = i as f32;
+                (x, 2.0 * x + 1.0)
+            })
+            .collect();
+        points.extend([(3.0, 40.0), (7.0, -12.0), (11.0, 0.0), (15.0, 90.0)]);
+        points
+    }
+
+    #[test]
+    fn ransac_line_fit() {
+        let points = contaminated_line();
+        let parameters = ConsensusParameters {
+            iterations: 50,
+            sample_size: 2,
+            inlier_threshold: 0.1,
+            scoring: ConsensusScoring::Ransac,
+            seed: 7,
+        };
+
+        let estimate = estimate(&points, &parameters, fit_line, line_residual)
+            .expect("Expected a line to be found.");
+
+        assert!((estimate.model.0 - 2.0).abs() < 1e-4);
+        assert!((estimate.model.1 - 1.0).abs() < 1e-3);
+        assert_eq!(estimate.inliers, (0..20).collect::<Vec<usize>>());
+        assert_eq!(estimate.cost, 4.0);
+    }
+
+    #[test]
+    fn msac_line_fit() {
+        let points = contaminated_line();
+        let parameters = ConsensusParameters {
+            iterations: 50,
+            sample_size: 2,
+            inlier_threshold: 0.1,
+            scoring: ConsensusScoring::Msac,
+            seed: 11,
+        };
+
+        let estimate = estimate(&points, &parameters, fit_line, line_residual)
+            .expect("Expected a line to be found.");
+
+        assert!((estimate.model.0 - 2.0).abs() < 1e-4);
+        assert_eq!(estimate.inliers.len(), 20);
+        assert!((estimate.cost - 4.0 * 0.01).abs() < 1e-4);
+    }
+
+    #[test]
+    fn ransac_keeps_candidate_over_worse_refit() {
+        let points = contaminated_line();
+        let parameters = ConsensusParameters {
+            iterations: 50,
+            sample_size: 2,
+            inlier_threshold: 0.1,
+            scoring: ConsensusScoring::Ransac,
+            seed: 7,
+        };
+
+        // Refitting to the inliers yields a useless model.
+        let fit = |points: &[&(f32, f32)]| match points.len() {
+            2 => fit_line(points),
+            _ => Some((0.0, 100.0)),
+        };
+        let estimate = estimate(&points, &parameters, fit, line_residual)
+            .expect("Expected a line to be found.");
+
+        assert!((estimate.model.0 - 2.0).abs() < 1e-4);
+        assert_eq!(estimate.inliers, (0..20).collect::<Vec<usize>>());
+        assert_eq!(estimate.cost, 4.0);
+    }
+
+    #[test]
+    fn ransac_too_few_data() {
+        let points = [(0.0, 1.0)];
+        let parameters = ConsensusParameters {
+            iterations: 10,
+            sample_size: 2,
+            inlier_threshold: 0.1,
+            scoring: ConsensusScoring::Ransac,
+            seed: 1,
+        };
+
+        assert!(estimate(&points, &parameters, fit_line, line_residual).is_none());
+    }
+}
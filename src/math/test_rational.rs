@@ -0,0 +1,84 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+#[cfg(test)]
+mod tests {
+    use crate::math::algebra::CrossProduct;
+    use crate::math::arrayalgebra::*;
+    use crate::math::rational::*;
+
+    #[test]
+    fn rational_lowest_terms() {
+        let a = Rational::new(6, -4);
+        assert_eq!(a.numerator(), -3);
+        assert_eq!(a.denominator(), 2);
+        assert_eq!(a, Rational::new(-9, 6));
+        assert_eq!(Rational::new(0, -5), Rational::integer(0));
+        assert_eq!(format!("{} {}", a, Rational::from(4)), "-3/2 4");
+    }
+
+    #[test]
+    fn rational_exact_arithmetic() {
+        let third = Rational::new(1, 3);
+        let sum = third + third + third;
+        assert_eq!(sum, Rational::integer(1));
+        assert_eq!(Rational::new(1, 2) - third, Rational::new(1, 6));
+        assert_eq!(
+            Rational::new(2, 3) * Rational::new(9, 4),
+            Rational::new(3, 2)
+        );
+        assert_eq!(
+            Rational::new(2, 3) / Rational::new(4, 9),
+            Rational::new(3, 2)
+        );
+        assert_eq!(Rational::integer(0).recip(), None);
+        assert_eq!(Rational::new(-2, 5).recip(), Some(Rational::new(-5, 2)));
+        assert!(Rational::new(1, 3) < Rational::new(1, 2));
+        assert!(Rational::new(-1, 2) < Rational::new(-1, 3));
+    }
+
+    #[test]
+    fn rational_exact_predicates() {
+        let r = Rational::integer;
+        // Collinearity via the cross product is exact, where 0.1 + 0.2 is not.
+        let a = make_array_vector([Rational::new(1, 10), Rational::new(2, 10), r(0)]);
+        let b = make_array_vector([Rational::new(3, 10), Rational::new(6, 10), r(0)]);
+        assert_eq!(a.cross(b), make_array_vector([r(0), r(0), r(0)]));
+
+        let m = make_array_matrix([[r(1), Rational::new(1, 2)], [Rational::new(1, 3), r(1)]]);
+        let v = make_array_vector([r(6), r(-6)]);
+        assert_eq!(m * v, make_array_vector([r(3), r(-4)]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn rational_zero_denominator() {
+        Rational::new(1, 0);
+    }
+}
@@ -0,0 +1,265 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+#[cfg(test)]
+mod tests {
+    use crate::math::arrayalgebra::*;
+    use crate::math::rotations::*;
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    const TOLERANCE: f32 = 1e-4;
+
+    fn assert_matrix_near(a: &ArrayMatrix<f32, 3, 3>, b: &ArrayMatrix<f32, 3, 3>) {
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!(
+                    (a.get(i, j) - b.get(i, j)).abs() < TOLERANCE,
+                    "{a:?} != {b:?}"
+                );
+            }
+        }
+    }
+
+    fn sample_angles() -> Vec<f32> {
+        (-6..=6).map(|i| i as f32 * 0.5).collect()
+    }
+
+    #[test]
+    fn rotations_wrap_angle() {
+        assert!((wrap_angle(3.0 * PI) - PI).abs() < TOLERANCE);
+        assert!((wrap_angle(-PI) - PI).abs() < TOLERANCE);
+        assert!((wrap_angle(2.0 * PI + 0.5) - 0.5).abs() < TOLERANCE);
+        assert!((wrap_angle(-0.5) + 0.5).abs() < TOLERANCE);
+    }
+
+    #[test]
+    fn rotations_quaternion_matrix_round_trip() {
+        for angle in sample_angles() {
+            let axis = make_array_vector([1.0, -2.0, 0.5]);
+            let m = matrix_from_axis_angle(axis, angle);
+            let q = quaternion_from_matrix(&m);
+
+            assert!((q.norm() - 1.0).abs() < TOLERANCE);
+            assert!(q.w >= 0.0);
+            assert_matrix_near(&matrix_from_quaternion(&q), &m);
+        }
+    }
+
+    #[test]
+    fn rotations_quaternion_rotates_vectors() {
+        let q = quaternion_from_axis_angle(make_array_vector([0.0, 0.0, 1.0]), FRAC_PI_2);
+        let v = q.rotate(make_array_vector([1.0, 0.0, 0.0]));
+        let expected = make_array_vector([0.0, 1.0, 0.0]);
+        let m = matrix_from_quaternion(&q);
+
+        for i in 0..3 {
+            assert!((v.get(i) - expected.get(i)).abs() < TOLERANCE);
+            assert!(
+                ((m * make_array_vector([1.0, 0.0, 0.0])).get(i) - expected.get(i)).abs()
+                    < TOLERANCE
+            );
+        }
+
+        let twice = q * q;
+        assert_matrix_near(
+            &matrix_from_quaternion(&twice),
+            &matrix_from_axis_angle(make_array_vector([0.0, 0.0, 1.0]), PI),
+        );
+    }
+
+    #[test]
+    fn rotations_axis_angle_edge_cases() {
+        let (axis, angle) = axis_angle_from_matrix(&ArrayMatrix::identity());
+        assert_eq!(angle, 0.0);
+        assert_eq!(axis, make_array_vector([1.0, 0.0, 0.0]));
+
+        // Half turns are where naive trace-based conversions break down.
+        let half_turn_axis = make_array_vector([1.0, 1.0, 0.0]) * (0.5_f32).sqrt();
+        let (axis, angle) = axis_angle_from_matrix(&matrix_from_axis_angle(half_turn_axis, PI));
+        assert!((angle - PI).abs() < TOLERANCE);
+        assert!(((axis * half_turn_axis).abs() - 1.0).abs() < TOLERANCE);
+
+        // Angles past pi come back as the shorter rotation about the flipped axis.
+        let z = make_array_vector([0.0, 0.0, 1.0]);
+        let (axis, angle) = axis_angle_from_quaternion(&quaternion_from_axis_angle(z, 1.5 * PI));
+        assert!((angle - 0.5 * PI).abs() < TOLERANCE);
+        assert!((axis * z + 1.0).abs() < TOLERANCE);
+    }
+
+    #[test]
+    fn rotations_euler_round_trip() {
+        for sequence in EulerSequence::ALL {
+            for a in sample_angles() {
+                for b in [-1.2f32, -0.4, 0.3, 0.7, 1.5, 2.5] {
+                    // Keep the middle angle within the sequence's canonical range.
+                    let b = if sequence.is_proper() {
+                        b.abs()
+                    } else {
+                        b.clamp(-1.5, 1.5)
+                    };
+                    let angles = [wrap_angle(a), b, wrap_angle(0.8 - a)];
+                    let m = matrix_from_euler(sequence, angles);
+                    let recovered = euler_from_matrix(sequence, &m);
+
+                    for (x, y) in angles.iter().zip(recovered.iter()) {
+                        assert!(
+                            wrap_angle(x - y).abs() < 1e-3,
+                            "{sequence:?}: {angles:?} != {recovered:?}"
+                        );
+                    }
+                    assert_matrix_near(
+                        &matrix_from_quaternion(&quaternion_from_euler(sequence, angles)),
+                        &m,
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rotations_euler_gimbal_lock() {
+        for sequence in EulerSequence::ALL {
+            let locks = if sequence.is_proper() {
+                [0.0, PI]
+            } else {
+                [FRAC_PI_2, -FRAC_PI_2]
+            };
+            for b in locks {
+                let m = matrix_from_euler(sequence, [0.3, b, 0.2]);
+                let recovered = euler_from_matrix(sequence, &m);
+
+                assert_eq!(recovered[2], 0.0);
+                assert_matrix_near(&matrix_from_euler(sequence, recovered), &m);
+            }
+        }
+    }
+
+    #[test]
+    fn rotations_euler_yaw_pitch_roll() {
+        let (yaw, pitch, roll) = (0.4, -0.3, 1.1);
+        let q = quaternion_from_euler(EulerSequence::ZYX, [yaw, pitch, roll]);
+        let expected = quaternion_from_axis_angle(make_array_vector([0.0, 0.0, 1.0]), yaw)
+            * quaternion_from_axis_angle(make_array_vector([0.0, 1.0, 0.0]), pitch)
+            * quaternion_from_axis_angle(make_array_vector([1.0, 0.0, 0.0]), roll);
+
+        assert_matrix_near(
+            &matrix_from_quaternion(&q),
+            &matrix_from_quaternion(&expected),
+        );
+        let angles = euler_from_quaternion(EulerSequence::ZYX, &q);
+        assert!((angles[0] - yaw).abs() < TOLERANCE);
+        assert!((angles[1] - pitch).abs() < TOLERANCE);
+        assert!((angles[2] - roll).abs() < TOLERANCE);
+    }
+
+    #[test]
+    fn rotations_euler_proper_zyz() {
+        let (a, b, c) = (0.4, 1.1, -0.7);
+        let z = make_array_vector([0.0, 0.0, 1.0]);
+        let y = make_array_vector([0.0, 1.0, 0.0]);
+        let expected = matrix_from_axis_angle(z, a)
+            * matrix_from_axis_angle(y, b)
+            * matrix_from_axis_angle(z, c);
+
+        assert_matrix_near(&matrix_from_euler(EulerSequence::ZYZ, [a, b, c]), &expected);
+        let angles = euler_from_matrix(EulerSequence::ZYZ, &expected);
+        assert!((angles[0] - a).abs() < TOLERANCE);
+        assert!((angles[1] - b).abs() < TOLERANCE);
+        assert!((angles[2] - c).abs() < TOLERANCE);
+        assert!(EulerSequence::ZYZ.is_proper());
+        assert!(!EulerSequence::ZYX.is_proper());
+    }
+
+    #[test]
+    fn rotations_axis_angle_type() {
+        let rotation = AxisAngle::new(make_array_vector([0.0, 0.0, 2.0]), FRAC_PI_2);
+        assert!((rotation.axis().get(2) - 1.0).abs() < TOLERANCE);
+
+        let rotated = rotation.rotate(make_array_vector([1.0, 0.0, 0.0]));
+        assert!(rotated.get(0).abs() < TOLERANCE);
+        assert!((rotated.get(1) - 1.0).abs() < TOLERANCE);
+
+        let q: Quaternion = rotation.into();
+        let back = AxisAngle::from(q);
+        assert!((back.angle() - FRAC_PI_2).abs() < TOLERANCE);
+        assert_matrix_near(
+            &AxisAngle::from_matrix(&rotation.to_matrix()).to_matrix(),
+            &rotation.to_matrix(),
+        );
+
+        let vector = rotation.rotation_vector();
+        assert!((vector.get(2) - FRAC_PI_2).abs() < TOLERANCE);
+        assert!((AxisAngle::from_rotation_vector(vector).angle() - FRAC_PI_2).abs() < TOLERANCE);
+
+        let identity = AxisAngle::new(make_array_vector([0.0, 0.0, 0.0]), 1.0);
+        assert_eq!(identity.angle(), 0.0);
+    }
+
+    #[test]
+    fn rotations_rotation_vector_small_angle() {
+        for length in [1e-8f32, 1e-5, 1e-3, 0.5, 3.0] {
+            let vector = make_array_vector([length * 0.6, -length * 0.8, 0.0]);
+            let q = quaternion_from_rotation_vector(vector);
+            assert!((q.norm() - 1.0).abs() < TOLERANCE);
+
+            let back = rotation_vector_from_quaternion(&q);
+            for i in 0..3 {
+                assert!((back.get(i) - vector.get(i)).abs() <= TOLERANCE * length.max(1e-3));
+            }
+        }
+    }
+
+    #[test]
+    fn rotations_slerp() {
+        let z = make_array_vector([0.0, 0.0, 1.0]);
+        let a = Quaternion::identity();
+        let b = quaternion_from_axis_angle(z, FRAC_PI_2);
+
+        let (_, angle) = axis_angle_from_quaternion(&slerp(&a, &b, 0.5));
+        assert!((angle - FRAC_PI_2 / 2.0).abs() < TOLERANCE);
+        let end = slerp(&a, &b, 1.0);
+        assert!((end.w - b.w).abs() < TOLERANCE && (end.z - b.z).abs() < TOLERANCE);
+
+        // -b is the same rotation; slerp still takes the short way round.
+        let negated = Quaternion::new(-b.w, -b.x, -b.y, -b.z);
+        let (_, angle) = axis_angle_from_quaternion(&slerp(&a, &negated, 0.5));
+        assert!((angle - FRAC_PI_2 / 2.0).abs() < TOLERANCE);
+
+        // Nearly identical rotations fall back to the linear blend.
+        let c = quaternion_from_axis_angle(z, 1e-7);
+        assert!((slerp(&a, &c, 0.5).norm() - 1.0).abs() < TOLERANCE);
+
+        let m = slerp_matrix(
+            &matrix_from_quaternion(&a),
+            &matrix_from_quaternion(&b),
+            0.5,
+        );
+        assert_matrix_near(&m, &matrix_from_axis_angle(z, FRAC_PI_2 / 2.0));
+    }
+}
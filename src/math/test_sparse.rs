@@ -0,0 +1,93 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+#[cfg(test)]
+mod tests {
+    use crate::math::sparse::*;
+
+    /// Returns the n x n 1D Laplacian with Dirichlet boundaries, a standard
+    /// sparse symmetric positive definite matrix.
+    fn laplacian(n: usize) -> CsrMatrix<f64> {
+        let mut triplets = vec![];
+        for i in 0..n {
+            triplets.push((i, i, 2.0));
+            if i > 0 {
+                triplets.push((i, i - 1, -1.0));
+                triplets.push((i - 1, i, -1.0));
+            }
+        }
+        CsrMatrix::from_triplets(n, n, &triplets).unwrap()
+    }
+
+    #[test]
+    fn csr_assembly_and_products() {
+        let m =
+            CsrMatrix::from_triplets(2, 3, &[(1, 2, 4.0), (0, 0, 1.0), (1, 2, 1.0), (0, 1, 2.0)])
+                .unwrap();
+        assert_eq!(m.nonzero_count(), 3);
+        assert_eq!(m.get(1, 2), 5.0);
+        assert_eq!(m.get(1, 0), 0.0);
+        assert_eq!(m.mul_vector(&[1.0, 1.0, 2.0]).unwrap(), vec![3.0, 10.0]);
+        assert_eq!(m.transpose().get(2, 1), 5.0);
+        assert_eq!(m.mul_vector(&[1.0]), Err(SparseFailure::DimensionMismatch));
+
+        assert_eq!(
+            CsrMatrix::from_triplets(2, 2, &[(2, 0, 1.0)]),
+            Err(SparseFailure::IndexOutOfBounds(2, 0))
+        );
+        let identity = CsrMatrix::<f64>::identity(3);
+        assert_eq!(
+            identity.mul_vector(&[1.0, 2.0, 3.0]).unwrap(),
+            vec![1.0, 2.0, 3.0]
+        );
+    }
+
+    #[test]
+    fn conjugate_gradient_solves_spd_system() {
+        let n = 50;
+        let a = laplacian(n);
+        let expected: Vec<f64> = (0..n).map(|i| (i as f64 * 0.3).sin()).collect();
+        let b = a.mul_vector(&expected).unwrap();
+
+        let result = conjugate_gradient(&a, &b, 1e-12, n).unwrap();
+        assert!(result.iterations <= n);
+        for (x, e) in result.solution.iter().zip(expected.iter()) {
+            assert!((x - e).abs() < 1e-9);
+        }
+
+        assert_eq!(
+            conjugate_gradient(&a, &b, 1e-12, 2),
+            Err(SparseFailure::NotConverged)
+        );
+        assert_eq!(
+            conjugate_gradient(&a, &b[1..], 1e-12, n),
+            Err(SparseFailure::DimensionMismatch)
+        );
+    }
+}
@@ -0,0 +1,274 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+#[cfg(test)]
+mod tests {
+    use crate::math::algebra::InnerProductSpace;
+    use crate::math::approx::ApproxEq;
+    use crate::math::arrayalgebra::*;
+    use crate::math::rotations::*;
+    use crate::math::spatial::*;
+    use std::f32::consts::FRAC_PI_2;
+
+    const TOLERANCE: f32 = 1e-5;
+
+    fn assert_near(a: ArrayVector<f32, 3>, b: ArrayVector<f32, 3>) {
+        assert!(a.distance(b) < TOLERANCE, "{a:?} != {b:?}");
+    }
+
+    fn sample_pose() -> SE3 {
+        SE3::from_quaternion(
+            &quaternion_from_axis_angle(make_array_vector([1.0, 2.0, -1.0]), 0.7),
+            make_array_vector([0.5, -1.0, 2.0]),
+        )
+    }
+
+    #[test]
+    fn se3_point_and_vector_action() {
+        let pose = SE3::from_quaternion(
+            &quaternion_from_axis_angle(make_array_vector([0.0, 0.0, 1.0]), FRAC_PI_2),
+            make_array_vector([1.0, 0.0, 0.0]),
+        );
+        let x = make_array_vector([1.0, 0.0, 0.0]);
+
+        assert_near(pose.transform_point(x), make_array_vector([1.0, 1.0, 0.0]));
+        assert_near(pose.transform_vector(x), make_array_vector([0.0, 1.0, 0.0]));
+    }
+
+    #[test]
+    fn se3_inverse_and_composition() {
+        let pose = sample_pose();
+        let p = make_array_vector([0.3, 0.2, -0.4]);
+
+        assert_near(pose.inverse().transform_point(pose.transform_point(p)), p);
+        assert_near((pose * pose.inverse()).transform_point(p), p);
+        assert_near(
+            (pose * pose).transform_point(p),
+            pose.transform_point(pose.transform_point(p)),
+        );
+        assert_eq!(SE3::identity().transform_point(p), p);
+    }
+
+    #[test]
+    fn se3_homogeneous_matrix() {
+        let pose = sample_pose();
+        let p = make_array_vector([0.3, 0.2, -0.4]);
+        let h = pose.to_matrix() * make_array_vector([p.get(0), p.get(1), p.get(2), 1.0]);
+
+        assert_near(
+            make_array_vector([h.get(0), h.get(1), h.get(2)]),
+            pose.transform_point(p),
+        );
+        assert_eq!(h.get(3), 1.0);
+        assert_eq!(SE3::from_matrix(&pose.to_matrix()), pose);
+        assert_eq!(SE3::try_from_matrix(&pose.to_matrix()), Some(pose));
+
+        let m = pose.to_matrix();
+        let rows: [[f32; 4]; 4] = std::array::from_fn(|i| std::array::from_fn(|j| m.get(i, j)));
+
+        let mut projective = rows;
+        projective[3][0] = 0.5;
+        assert_eq!(SE3::try_from_matrix(&make_array_matrix(projective)), None);
+
+        let mut scaled = rows;
+        for (i, row) in scaled.iter_mut().enumerate().take(3) {
+            row[i] *= 2.0;
+        }
+        assert_eq!(SE3::try_from_matrix(&make_array_matrix(scaled)), None);
+
+        let mut reflected = rows;
+        reflected[2] = reflected[2].map(|value| -value);
+        assert_eq!(SE3::try_from_matrix(&make_array_matrix(reflected)), None);
+    }
+
+    #[test]
+    fn spatial_twist_adjoint() {
+        let pose = sample_pose();
+        let twist = Twist::new(
+            make_array_vector([0.1, -0.2, 0.3]),
+            make_array_vector([1.0, 0.5, -0.5]),
+        );
+        let moved = pose.transform_twist(&twist);
+        let via_matrix = pose.adjoint() * twist.to_vector();
+
+        for i in 0..6 {
+            assert!((moved.to_vector().get(i) - via_matrix.get(i)).abs() < TOLERANCE);
+        }
+
+        // A pure translation leaves angular velocity alone and adds p x w.
+        let offset = SE3::new(ArrayMatrix::identity(), make_array_vector([1.0, 0.0, 0.0]));
+        let spin = Twist::new(
+            make_array_vector([0.0, 0.0, 1.0]),
+            make_array_vector([0.0; 3]),
+        );
+        let moved = offset.transform_twist(&spin);
+        assert_near(moved.angular, spin.angular);
+        assert_near(moved.linear, make_array_vector([0.0, -1.0, 0.0]));
+    }
+
+    #[test]
+    fn spatial_wrench_power_is_frame_invariant() {
+        let pose = sample_pose();
+        let twist = Twist::new(
+            make_array_vector([0.1, -0.2, 0.3]),
+            make_array_vector([1.0, 0.5, -0.5]),
+        );
+        let wrench = Wrench::new(
+            make_array_vector([0.0, 2.0, -1.0]),
+            make_array_vector([3.0, -1.0, 0.5]),
+        );
+
+        let before = wrench.power(&twist);
+        let after = pose
+            .transform_wrench(&wrench)
+            .power(&pose.transform_twist(&twist));
+        assert!((before - after).abs() < 1e-4);
+
+        assert_eq!((twist + -twist), Twist::zero());
+        assert_eq!(wrench * 0.0, Wrench::zero());
+    }
+
+    #[test]
+    fn se3_exp_log_round_trip() {
+        for twist in [
+            Twist::new(
+                make_array_vector([0.3, -0.2, 0.9]),
+                make_array_vector([1.0, 2.0, -0.5]),
+            ),
+            Twist::new(
+                make_array_vector([1e-5, 0.0, 0.0]),
+                make_array_vector([0.0, 1.0, 0.0]),
+            ),
+            Twist::zero(),
+        ] {
+            let back = SE3::exp(&twist).log();
+            assert_near(back.angular, twist.angular);
+            assert_near(back.linear, twist.linear);
+        }
+
+        // A quarter turn about z while advancing along z is a screw motion.
+        let screw = SE3::exp(&Twist::new(
+            make_array_vector([0.0, 0.0, FRAC_PI_2]),
+            make_array_vector([0.0, 0.0, 1.0]),
+        ));
+        assert_near(screw.translation(), make_array_vector([0.0, 0.0, 1.0]));
+    }
+
+    #[test]
+    fn se3_screw_interpolation() {
+        let start = sample_pose();
+        let end = SE3::from_quaternion(
+            &quaternion_from_axis_angle(make_array_vector([1.0, 0.0, 0.0]), 1.0),
+            make_array_vector([-1.0, 0.5, 2.0]),
+        );
+
+        let at_start = start.interpolate(&end, 0.0);
+        let at_end = start.interpolate(&end, 1.0);
+        assert_near(at_start.translation(), start.translation());
+        assert_near(at_end.translation(), end.translation());
+        let point = make_array_vector([0.2, -0.4, 1.0]);
+        assert_near(at_end.transform_point(point), end.transform_point(point));
+
+        // The rotation part of a screw motion follows slerp.
+        let halfway = start.interpolate(&end, 0.5);
+        let expected = slerp(&start.quaternion(), &end.quaternion(), 0.5);
+        let q = halfway.quaternion();
+        assert!(
+            (q.w * expected.w + q.x * expected.x + q.y * expected.y + q.z * expected.z).abs()
+                > 1.0 - 1e-4
+        );
+    }
+
+    #[test]
+    fn se3_display() {
+        let pose =
+            SE3::from_quaternion(&Quaternion::identity(), make_array_vector([1.0, 2.0, 3.0]));
+        assert_eq!(
+            format!("{pose:.1}"),
+            "[1.0, 0.0, 0.0, 1.0]\n[0.0, 1.0, 0.0, 2.0]\n[0.0, 0.0, 1.0, 3.0]\n[0.0, 0.0, 0.0, 1.0]"
+        );
+        assert_eq!(
+            format!("{:.2}", Quaternion::identity()),
+            "1.00 +0.00i +0.00j +0.00k"
+        );
+    }
+
+    #[test]
+    fn se3_orthonormalized_removes_drift() {
+        let step = SE3::from_quaternion(
+            &quaternion_from_axis_angle(make_array_vector([1.0, 2.0, 3.0]), 0.1),
+            make_array_vector([0.1, 0.0, 0.0]),
+        );
+        let mut pose = SE3::identity();
+        for _ in 0..1000 {
+            pose = pose * step;
+        }
+
+        let pose = pose.orthonormalized();
+        let r = pose.rotation();
+        assert!((r.transpose() * r).abs_diff_eq(&ArrayMatrix::identity(), 1e-6));
+    }
+
+    #[test]
+    fn se3_constructors() {
+        let x = make_array_vector([1.0, 0.0, 0.0]);
+        let z = make_array_vector([0.0, 0.0, 1.0]);
+        let (a, alpha, d, theta) = (0.4, 0.3, 0.2, 1.1);
+
+        let expected = SE3::from_axis_angle(z, theta, make_array_vector([0.0; 3]))
+            * SE3::from_axis_angle(x, 0.0, z * d)
+            * SE3::from_axis_angle(x, alpha, x * a);
+        assert!(SE3::from_dh(a, alpha, d, theta).abs_diff_eq(&expected, 1e-6));
+
+        let expected = SE3::from_axis_angle(x, alpha, make_array_vector([0.0; 3]))
+            * SE3::from_axis_angle(z, theta, x * a)
+            * SE3::from_axis_angle(z, 0.0, z * d);
+        assert!(SE3::from_modified_dh(a, alpha, d, theta).abs_diff_eq(&expected, 1e-6));
+
+        let pose = SE3::from_axis_angle(z, FRAC_PI_2, make_array_vector([1.0, 2.0, 3.0]));
+        assert_near(pose.transform_point(x), make_array_vector([1.0, 3.0, 3.0]));
+        assert_eq!(pose.to_matrix().get(1, 3), 2.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn se3_serde_rejects_invalid_rotation() {
+        let pose = sample_pose();
+        let json = serde_json::to_string(&pose).unwrap();
+        let restored: SE3 = serde_json::from_str(&json).unwrap();
+        assert!(restored.abs_diff_eq(&pose, 1e-6));
+
+        let skewed = SE3::new(
+            make_array_matrix([[2.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]),
+            make_array_vector([0.0; 3]),
+        );
+        let json = serde_json::to_string(&skewed).unwrap();
+        assert!(serde_json::from_str::<SE3>(&json).is_err());
+    }
+}
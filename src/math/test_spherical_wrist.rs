@@ -0,0 +1,129 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+#[cfg(test)]
+mod tests {
+    use crate::math::approx::ApproxEq;
+    use crate::math::arrayalgebra::*;
+    use crate::math::rotations::*;
+    use crate::math::spatial::*;
+    use crate::math::spherical_wrist::*;
+    use std::f32::consts::FRAC_PI_2;
+
+    /// A small industrial arm, with the offsets and sign corrections that map
+    /// its joint conventions to the model's.
+    fn arm() -> SphericalWristArm {
+        SphericalWristArm {
+            offsets: [0.0, -FRAC_PI_2, 0.0, 0.0, 0.0, 0.0],
+            sign_corrections: [-1.0, 1.0, 1.0, -1.0, 1.0, -1.0],
+            ..SphericalWristArm::new(0.025, -0.035, 0.0, 0.4, 0.315, 0.365, 0.08)
+        }
+    }
+
+    fn assert_pose_near(a: &SE3, b: &SE3) {
+        assert!(a.abs_diff_eq(b, 1e-3), "{a:.4}\n!=\n{b:.4}");
+    }
+
+    #[test]
+    fn spherical_wrist_zero_configuration() {
+        let arm = SphericalWristArm::new(0.0, 0.0, 0.0, 1.0, 2.0, 3.0, 0.5);
+        let pose = arm.forward(&[0.0; 6]);
+        assert!(pose
+            .translation()
+            .abs_diff_eq(&make_array_vector([0.0, 0.0, 6.5]), 1e-6));
+        assert!(pose.rotation().abs_diff_eq(&ArrayMatrix::identity(), 1e-6));
+    }
+
+    #[test]
+    fn spherical_wrist_all_branches_reach_pose() {
+        let arm = arm();
+        // Poses far from the base cannot be reached with the shoulder flipped.
+        let configurations = [
+            ([0.2, 0.3, -0.4, 0.5, 0.6, -0.7], 4),
+            ([-1.0, -0.2, 0.9, -1.5, -0.8, 2.0], 8),
+            ([2.5, 0.1, 0.3, 0.0, 1.2, 0.4], 4),
+        ];
+
+        for (q, count) in configurations {
+            let pose = arm.forward(&q);
+            let solutions = arm.inverse(&pose);
+            let reached: Vec<JointAngles> = solutions.iter().flatten().copied().collect();
+            assert_eq!(reached.len(), count);
+
+            for solution in reached.iter() {
+                assert_pose_near(&arm.forward(solution), &pose);
+            }
+
+            let nearest = arm.inverse_nearest(&pose, &q).unwrap();
+            for (a, b) in nearest.iter().zip(q.iter()) {
+                assert!(wrap_angle(a - b).abs() < 1e-3);
+            }
+
+            let branch = ArmBranch::all()
+                .into_iter()
+                .find(|branch| arm.inverse_in_branch(&pose, *branch) == Some(nearest));
+            assert!(branch.is_some());
+        }
+    }
+
+    #[test]
+    fn spherical_wrist_singularity() {
+        let arm = arm();
+        let pose = arm.forward(&[0.2, 0.3, -0.4, 0.5, 0.0, -0.7]);
+        let reached: Vec<JointAngles> = arm.inverse(&pose).iter().flatten().copied().collect();
+        assert_eq!(reached.len(), 4);
+        for solution in reached.iter() {
+            assert_pose_near(&arm.forward(solution), &pose);
+        }
+    }
+
+    #[test]
+    fn spherical_wrist_stretched_elbow() {
+        let arm = arm();
+        let stretched = -arm.a2.atan2(arm.c3);
+        for shoulder in [-0.6, -0.2, 0.1, 0.3, 0.7] {
+            let pose = arm.forward(&[0.2, shoulder, stretched, 0.5, 0.6, -0.7]);
+            let reached: Vec<JointAngles> = arm.inverse(&pose).iter().flatten().copied().collect();
+            assert_eq!(reached.len(), 4, "{shoulder}");
+            for solution in reached.iter() {
+                assert_pose_near(&arm.forward(solution), &pose);
+            }
+        }
+    }
+
+    #[test]
+    fn spherical_wrist_unreachable_pose() {
+        let far = SE3::from_quaternion(&Quaternion::identity(), make_array_vector([5.0, 0.0, 0.0]));
+        assert!(arm()
+            .inverse(&far)
+            .iter()
+            .all(|solution| solution.is_none()));
+        assert_eq!(arm().inverse_nearest(&far, &[0.0; 6]), None);
+    }
+}
@@ -0,0 +1,58 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+#[cfg(test)]
+mod tests {
+    use crate::math::rotations::wrap_angle;
+    use crate::math::spherical_wrist::SphericalWristArm;
+    use crate::wasm::*;
+
+    #[test]
+    fn wasm_arm_round_trip() {
+        let arm = WasmArm::new(0.025, -0.035, 0.0, 0.4, 0.315, 0.365, 0.08);
+        let joints = [0.2, 0.3, -0.4, 0.5, 0.6, -0.7];
+
+        let pose = arm.forward(&joints).unwrap();
+        let expected = SphericalWristArm::new(0.025, -0.035, 0.0, 0.4, 0.315, 0.365, 0.08)
+            .forward(&joints)
+            .to_matrix();
+        assert_eq!(pose.len(), 16);
+        assert_eq!(pose[3], expected.get(0, 3));
+        assert_eq!(pose[15], 1.0);
+
+        let solutions = arm.inverse(&pose).unwrap();
+        assert_eq!(solutions.len(), 48);
+        assert!(solutions[..6].iter().all(|angle| angle.is_finite()));
+
+        let nearest = arm.inverse_nearest(&pose, &joints).unwrap().unwrap();
+        for (a, b) in nearest.iter().zip(joints.iter()) {
+            assert!(wrap_angle(a - b).abs() < 1e-3);
+        }
+    }
+}
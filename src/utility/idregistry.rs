@@ -36,9 +36,12 @@ SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 use std::borrow::BorrowMut;
 use std::cmp::min;
 use std::collections::{HashSet, LinkedList};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
 
 /// Identifier Registry Failures.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum IdentifierRegistryFailure {
     /// Reported when the registry runs out of unique identifiers.
     OutOfIdentifiers,
@@ -51,6 +54,24 @@ pub enum IdentifierRegistryFailure {
     IdentiferAlreadyReleased,
 }
 
+impl Display for IdentifierRegistryFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IdentifierRegistryFailure::OutOfIdentifiers => {
+                write!(f, "Registry ran out of unique identifiers.")
+            }
+            IdentifierRegistryFailure::InvalidIdentifier => {
+                write!(f, "Identifier is not valid for this registry.")
+            }
+            IdentifierRegistryFailure::IdentiferAlreadyReleased => {
+                write!(f, "Identifier was already released.")
+            }
+        }
+    }
+}
+
+impl Error for IdentifierRegistryFailure {}
+
 /// Identifier Registry Trait.
 ///
 /// Identifier registries support acquisition and release operation for unique
@@ -75,10 +96,24 @@ pub trait IdentifierRegistry<Identifier: Clone + Eq>: Clone {
     fn release_id(&mut self, id: Identifier) -> Result<(), IdentifierRegistryFailure>;
 }
 
+/// Queryable Identifier Registry Trait.
+///
+/// Identifier registries that can also report whether a given identifier is
+/// in use, which lets their users check that the registry agrees with the
+/// objects they identify.
+pub trait QueryableIdentifierRegistry<Identifier: Clone + Eq>:
+    IdentifierRegistry<Identifier>
+{
+    /// Checks if the provided identifier is not in use, i.e., if the registry
+    /// could hand it out from some future call of acquire_id.
+    fn is_free(&self, id: &Identifier) -> bool;
+}
+
 /// Explicit, Integral Identifier Registry.
 ///
 /// This registry maintains a list of available and in-use integer identifiers.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExplicitIntegralIdentifierRegistry {
     all_ids: HashSet<usize>,
     free_ids: HashSet<usize>,
@@ -149,6 +184,12 @@ impl IdentifierRegistry<usize> for ExplicitIntegralIdentifierRegistry {
     }
 }
 
+impl QueryableIdentifierRegistry<usize> for ExplicitIntegralIdentifierRegistry {
+    fn is_free(&self, id: &usize) -> bool {
+        !self.all_ids.contains(id) || self.free_ids.contains(id)
+    }
+}
+
 impl ExplicitIntegralIdentifierRegistry {
     /// Build a registry with a non-zero initial size.
     pub fn new(initial_size: usize) -> Self {
@@ -104,6 +104,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::needless_late_init)]
     fn idregistry_double_release() {
         let mut registry = ExplicitIntegralIdentifierRegistry::new(2);
         let id1;
@@ -118,4 +119,19 @@ mod tests {
             "Successfully freed an identifier that was already freed when not expected.",
         );
     }
+
+    #[test]
+    fn idregistry_is_free() {
+        let mut registry = ExplicitIntegralIdentifierRegistry::new(2);
+        let id = registry
+            .acquire_id()
+            .expect("Failed to acquire an identifier when expected.");
+        assert!(!registry.is_free(&id));
+        assert!(registry.is_free(&1));
+        assert!(registry.is_free(&100));
+        registry
+            .release_id(id)
+            .expect("Failed to release an identifier when expected.");
+        assert!(registry.is_free(&id));
+    }
 }
@@ -0,0 +1,126 @@
+/*
+Copyright 2024 Rollen S. D'Souza
+
+Redistribution and use in source and binary forms, with or without modification,
+are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its contributors
+   may be used to endorse or promote products derived from this software without
+   specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS “AS IS” AND
+ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED
+WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR
+ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES
+(INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES;
+LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON
+ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+(INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+//! WebAssembly module.
+//!
+//! A small wasm-bindgen interface, available with the `wasm` feature, for
+//! driving the kinematics from JavaScript, e.g. in interactive browser demos.
+//! Poses cross the boundary as 4x4 homogeneous matrices flattened in row-major
+//! order, and joint configurations as arrays of six angles in radians. Poses
+//! that are not rigid transformations are rejected.
+
+use crate::math::arrayalgebra::make_array_matrix;
+use crate::math::spatial::SE3;
+use crate::math::spherical_wrist::{JointAngles, SphericalWristArm};
+use wasm_bindgen::prelude::*;
+
+/// Spherical-wrist arm, as exposed to JavaScript.
+#[wasm_bindgen]
+pub struct WasmArm {
+    arm: SphericalWristArm,
+}
+
+#[wasm_bindgen]
+impl WasmArm {
+    /// Builds an arm with no joint offsets or sign corrections; see
+    /// `SphericalWristArm` for the meaning of the parameters.
+    #[wasm_bindgen(constructor)]
+    pub fn new(a1: f32, a2: f32, b: f32, c1: f32, c2: f32, c3: f32, c4: f32) -> WasmArm {
+        WasmArm {
+            arm: SphericalWristArm::new(a1, a2, b, c1, c2, c3, c4),
+        }
+    }
+
+    /// Sets the joint offsets and sign corrections mapping the robot's joint
+    /// values to the model's.
+    #[wasm_bindgen(js_name = setJointConventions)]
+    pub fn set_joint_conventions(
+        &mut self,
+        offsets: &[f32],
+        sign_corrections: &[f32],
+    ) -> Result<(), JsError> {
+        self.arm.offsets = joint_angles(offsets)?;
+        self.arm.sign_corrections = joint_angles(sign_corrections)?;
+        Ok(())
+    }
+
+    /// Returns the pose of the flange for the given joint angles.
+    pub fn forward(&self, joints: &[f32]) -> Result<Vec<f32>, JsError> {
+        let pose = self.arm.forward(&joint_angles(joints)?);
+        Ok(pose_to_vec(&pose))
+    }
+
+    /// Returns the joint angles of all eight branches reaching the pose,
+    /// concatenated in the order of `ArmBranch::all`; the angles of branches
+    /// that cannot reach the pose are NaN.
+    pub fn inverse(&self, pose: &[f32]) -> Result<Vec<f32>, JsError> {
+        let solutions = self.arm.inverse(&pose_from_slice(pose)?);
+        Ok(solutions
+            .iter()
+            .flat_map(|solution| solution.unwrap_or([f32::NAN; 6]))
+            .collect())
+    }
+
+    /// Returns the joint angles reaching the pose that are closest to the
+    /// seed configuration, or `undefined` if the pose is out of reach.
+    #[wasm_bindgen(js_name = inverseNearest)]
+    pub fn inverse_nearest(&self, pose: &[f32], seed: &[f32]) -> Result<Option<Vec<f32>>, JsError> {
+        let solution = self
+            .arm
+            .inverse_nearest(&pose_from_slice(pose)?, &joint_angles(seed)?);
+        Ok(solution.map(|q| q.to_vec()))
+    }
+}
+
+fn joint_angles(values: &[f32]) -> Result<JointAngles, JsError> {
+    values
+        .try_into()
+        .map_err(|_| JsError::new("Expected six joint values."))
+}
+
+fn pose_from_slice(values: &[f32]) -> Result<SE3, JsError> {
+    let values: &[f32; 16] = values
+        .try_into()
+        .map_err(|_| JsError::new("Expected a 4x4 row-major homogeneous matrix."))?;
+    let rows: [[f32; 4]; 4] = std::array::from_fn(|i| std::array::from_fn(|j| values[4 * i + j]));
+    SE3::try_from_matrix(&make_array_matrix(rows)).ok_or_else(|| {
+        JsError::new(
+            "Expected a rigid transformation: an orthonormal rotation and a bottom row of \
+             [0, 0, 0, 1].",
+        )
+    })
+}
+
+fn pose_to_vec(pose: &SE3) -> Vec<f32> {
+    let m = pose.to_matrix();
+    (0..4)
+        .flat_map(|i| (0..4).map(move |j| (i, j)))
+        .map(|(i, j)| m.get(i, j))
+        .collect()
+}